@@ -4,6 +4,31 @@
 use std::collections::HashMap;
 use pcre::Pcre;
 use itertools::Itertools;
+use crate::uzbek::alphabetic::{self, SortOptions};
+use crate::uzbek::stopwords::Stopwords;
+use crate::uzbek::tokenize::{self, TokenKind};
+use crate::uzbek::{letter, script, stem, Script};
+
+/// Options for [`count_with`].
+///
+/// All options are off by default, so words are counted as in [`count`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CountOptions {
+    /// Lowercases words, so "Salom" and "salom" are one word.
+    pub lowercase: bool,
+    /// Splits words by [`tokenize::words`] and skips punctuation marks,
+    /// so "salom," and "salom." are one word.
+    pub strip_punctuation: bool,
+    /// Unifies apostrophe variants to ‘, so "o'zbek" and "oʻzbek" are one word.
+    pub unify_apostrophes: bool,
+    /// Converts words to a single script, so "salom" and "салом" are one word.
+    pub script: Option<Script>,
+    /// Counts stems found by [`stem`] instead of words, so "kitob" and "kitoblar" are one word.
+    /// Stems are always lowercased with unified apostrophes.
+    pub stem: bool,
+    /// Skips words found in the stopwords before stemming.
+    pub stopwords: Option<Stopwords>,
+}
 
 /// Provides a list of words and their number of occurences in a given text.
 ///
@@ -27,10 +52,7 @@ pub fn count(text: &str) -> HashMap<String, usize> {
     let mut result: HashMap<String, usize> = HashMap::new();
     let mut pre_result: HashMap<&str, usize> = HashMap::new();
 
-    let mut re = Pcre::compile(r"[\p{Cyrillic}|\p{Latin}|0-9|\-_]+").unwrap();
-    let matches = re.matches(&text);
-
-    if matches.count() > 0 {
+    if has_words(text) {
         let input_vec = text.split_whitespace();
         pre_result = input_vec.into_iter().counts();
     }
@@ -42,6 +64,130 @@ pub fn count(text: &str) -> HashMap<String, usize> {
     result
 }
 
+/// Provides a list of words and their number of occurences without stopwords.
+///
+/// Works as [`count`], but skips words found in the given [`Stopwords`].
+///
+/// # Example
+///```rust
+/// use std::collections::HashMap;
+/// use korrektor::utils::frequency;
+/// use korrektor::uzbek::stopwords::Stopwords;
+///
+/// let output = frequency::count_excluding("kitob va daftar va kitob", &Stopwords::new());
+/// let expected = HashMap::from([
+///    (String::from("kitob"), 2),
+///    (String::from("daftar"), 1)]);
+/// assert_eq!(output, expected);
+/// ```
+pub fn count_excluding(text: &str, stopwords: &Stopwords) -> HashMap<String, usize> {
+    let mut result = count(text);
+    result.retain(|word, _| !stopwords.contains(word));
+
+    result
+}
+
+/// Provides the most frequent words of a text without stopwords.
+///
+/// Given a String returns up to `limit` words with their number of occurrences,
+/// the most frequent first. Words with the same number of occurrences
+/// follow the Uzbek alphabetic order.
+///
+/// # Example
+///```rust
+/// use korrektor::utils::frequency;
+/// use korrektor::uzbek::stopwords::Stopwords;
+///
+/// let output = frequency::keywords("bu kitob va bu daftar va bu kitob", &Stopwords::new(), 2);
+/// assert_eq!(output, vec![(String::from("kitob"), 2), (String::from("daftar"), 1)]);
+/// ```
+pub fn keywords(text: &str, stopwords: &Stopwords, limit: usize) -> Vec<(String, usize)> {
+    let options = SortOptions::default();
+    let mut result: Vec<(String, usize)> = count_excluding(text, stopwords).into_iter().collect();
+
+    result.sort_by(|(word1, count1), (word2, count2)| {
+        count2.cmp(count1).then_with(|| alphabetic::compare(word1, word2, &options))
+    });
+    result.truncate(limit);
+
+    result
+}
+
+/// Provides a list of normalized words and their number of occurences.
+///
+/// Works as [`count`] with words normalized according to the given [`CountOptions`].
+/// Words are converted to the chosen script first, then apostrophes are unified
+/// and words are lowercased, stopwords are skipped and the rest are stemmed.
+///
+/// # Example
+///```rust
+/// use std::collections::HashMap;
+/// use korrektor::utils::frequency::{self, CountOptions};
+/// use korrektor::uzbek::Script;
+///
+/// let options = CountOptions { lowercase: true, strip_punctuation: true, ..Default::default() };
+/// let output = frequency::count_with("Salom, salom salom.", &options);
+/// assert_eq!(output, HashMap::from([(String::from("salom"), 3)]));
+///
+/// let options = CountOptions { script: Some(Script::Latin), stem: true, ..Default::default() };
+/// let output = frequency::count_with("kitob китоблар", &options);
+/// assert_eq!(output, HashMap::from([(String::from("kitob"), 2)]));
+/// ```
+pub fn count_with(text: &str, options: &CountOptions) -> HashMap<String, usize> {
+    if !has_words(text) {
+        return HashMap::new();
+    }
+
+    let words: Vec<&str> = if options.strip_punctuation {
+        tokenize::words(text)
+            .into_iter()
+            .filter(|token| token.kind != TokenKind::Punctuation)
+            .map(|token| token.text)
+            .collect()
+    } else {
+        text.split_whitespace().collect()
+    };
+
+    let mut result: HashMap<String, usize> = HashMap::new();
+
+    for word in words {
+        let mut word = match options.script {
+            Some(Script::Latin) => script::to_latin(word),
+            Some(Script::Cyrillic) => script::to_cyrillic(word),
+            None => word.to_string(),
+        };
+
+        if options.unify_apostrophes {
+            word = word.chars().map(|value| if letter::is_apostrophe(value) { '‘' } else { value }).collect();
+        }
+
+        if options.lowercase {
+            word = word.to_lowercase();
+        }
+
+        if options.stopwords.as_ref().is_some_and(|stopwords| stopwords.contains(&word)) {
+            continue;
+        }
+
+        if options.stem {
+            word = stem(&word);
+        }
+
+        if !word.is_empty() {
+            *result.entry(word).or_insert(0) += 1;
+        }
+    }
+
+    result
+}
+
+/// Checks whether a text has any letters, digits, hyphens or underscores.
+fn has_words(text: &str) -> bool {
+    let mut re = Pcre::compile(r"[\p{Cyrillic}|\p{Latin}|0-9|\-_]+").unwrap();
+
+    re.matches(text).count() > 0
+}
+
 #[cfg(test)]
 mod as_tests {
     use super::*;
@@ -51,4 +197,67 @@ mod as_tests {
         assert_eq!(count("salom xato quyosh salom mushuk"),
                    HashMap::from([(String::from("salom"), 2), (String::from("xato"), 1), (String::from("quyosh"), 1), (String::from("mushuk"), 1)]));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn count_excluding_test() {
+        let mut stopwords = Stopwords::new();
+        stopwords.insert("salom");
+
+        assert_eq!(count_excluding("salom va xato uchun quyosh bilan salom", &stopwords),
+                   HashMap::from([(String::from("xato"), 1), (String::from("quyosh"), 1)]));
+        assert_eq!(count_excluding("ва бу китоб", &Stopwords::new()), HashMap::from([(String::from("китоб"), 1)]));
+        assert_eq!(count_excluding("va bu", &Stopwords::empty()).len(), 2);
+    }
+
+    #[test]
+    fn keywords_test() {
+        let text = "olma va nok bilan olma uchun gilos nok olma";
+
+        assert_eq!(keywords(text, &Stopwords::new(), 10),
+                   vec![(String::from("olma"), 3), (String::from("nok"), 2), (String::from("gilos"), 1)]);
+        assert_eq!(keywords(text, &Stopwords::new(), 1), vec![(String::from("olma"), 3)]);
+        assert!(keywords("va bilan", &Stopwords::new(), 5).is_empty());
+    }
+
+    #[test]
+    fn count_with_test() {
+        let text = "Salom, salom salom. O'zbek oʻzbek";
+
+        assert_eq!(count_with(text, &CountOptions::default()), count(text));
+        assert_eq!(count_with("!!! ...", &CountOptions::default()), count("!!! ..."));
+        assert!(count_with("!!!", &CountOptions::default()).is_empty());
+        assert_eq!(count_with(text, &CountOptions { strip_punctuation: true, ..Default::default() }),
+                   HashMap::from([(String::from("Salom"), 1), (String::from("salom"), 2),
+                                  (String::from("O'zbek"), 1), (String::from("oʻzbek"), 1)]));
+        assert_eq!(count_with(text, &CountOptions { lowercase: true, strip_punctuation: true, unify_apostrophes: true, ..Default::default() }),
+                   HashMap::from([(String::from("salom"), 3), (String::from("o‘zbek"), 2)]));
+    }
+
+    #[test]
+    fn count_with_script_test() {
+        let text = "Салом salom ўзбек o‘zbek";
+        let options = CountOptions { lowercase: true, unify_apostrophes: true, script: Some(Script::Latin), ..Default::default() };
+        assert_eq!(count_with(text, &options), HashMap::from([(String::from("salom"), 2), (String::from("o‘zbek"), 2)]));
+
+        let options = CountOptions { lowercase: true, script: Some(Script::Cyrillic), ..Default::default() };
+        assert_eq!(count_with(text, &options), HashMap::from([(String::from("салом"), 2), (String::from("ўзбек"), 2)]));
+    }
+
+    #[test]
+    fn count_with_stem_test() {
+        let text = "Kitoblar va kitobim bilan, bolalar uchun kitob!";
+        let options = CountOptions { strip_punctuation: true, stem: true, stopwords: Some(Stopwords::new()), ..Default::default() };
+
+        assert_eq!(count_with(text, &options), HashMap::from([(String::from("kitob"), 3), (String::from("bola"), 1)]));
+        assert!(count_with("", &options).is_empty());
+
+        let options = CountOptions { lowercase: true, stem: true, ..Default::default() };
+        assert_eq!(count_with("Odam odamlar qalam qalamlar", &options),
+                   HashMap::from([(String::from("odam"), 2), (String::from("qalam"), 2)]));
+
+        let options = CountOptions { lowercase: true, strip_punctuation: true, stem: true, ..Default::default() };
+        assert_eq!(count_with("Yozuvchi yozuvchilar narsa narsalar haqida yozdi.", &options),
+                   HashMap::from([(String::from("yozuvchi"), 2), (String::from("narsa"), 2),
+                                  (String::from("haqida"), 1), (String::from("yoz"), 1)]));
+    }
+}
@@ -1,15 +1,98 @@
 //! Functions to sort Uzbek words.
 //!
 //! Both cyrillic and latin modes can be used.
+//! [`sort`] keeps the historical order with capital letters after small ones,
+//! while [`sort_with`] and [`compare`] use a multi-level collation
+//! configured by [`SortOptions`].
+use std::cmp::Ordering;
 use regex::Regex;
+use crate::uzbek::{letter, script};
 
-const CHAR_ORDER: [&str; 128] = [
+/// Comparison strength of the collation.
+///
+/// Each level only breaks ties left by the levels before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Strength {
+    /// Letters only: case, apostrophe variants and punctuation are ignored.
+    Primary,
+    /// Letters, then letter case with small letters first.
+    Secondary,
+    /// Letters, case, then every remaining difference such as
+    /// apostrophe variants and punctuation.
+    Tertiary,
+}
+
+/// Options for [`sort_with`] and [`compare`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortOptions {
+    /// Level up to which words are distinguished. Defaults to [`Strength::Tertiary`].
+    pub strength: Strength,
+    /// Compares cyrillic words through their latin letters,
+    /// so "Алишер" sorts next to "Alisher" instead of after "Zafar".
+    /// Words keep their original script in the output.
+    pub script_agnostic: bool,
+    /// Compares runs of digits by their numeric value,
+    /// so "2-sinf" comes before "10-sinf".
+    pub natural: bool,
+    /// Sorts in descending order. Equal words still keep their input order.
+    pub descending: bool,
+    /// Keeps only the first of the words that are equal at the chosen strength.
+    pub dedup: bool,
+    /// Keeps the first line on top in [`sort_lines`] and [`sort_records`].
+    pub header: bool,
+}
+
+impl Default for SortOptions {
+    fn default() -> Self {
+        SortOptions {
+            strength: Strength::Tertiary,
+            script_agnostic: false,
+            natural: false,
+            descending: false,
+            dedup: false,
+            header: false,
+        }
+    }
+}
+
+/// Primary collation element, numbers sort before any char.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Weight {
+    /// Run of digits in natural mode: count of significant digits, then the digits.
+    Number(usize, String),
+    Char(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct SortKey {
+    primary: Vec<Weight>,
+    secondary: Vec<u8>,
+    tertiary: String,
+}
+
+/// Latin letters in the official Uzbek alphabet order.
+///
+/// Digraphs are represented by the placeholders produced by `to_sortable`.
+const LATIN_ORDER: [&str; 58] = [
     "ê", "a", "b", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u", "v", "x", "y", "z", "ŏ", "ğ", "š", "č",
-    "Ê", "A", "B", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S", "T", "U", "V", "X", "Y", "Z", "Ŏ", "Ğ", "Š", "Č",
-    "а", "б", "д", "г", "д", "е", "ё", "ж", "з", "и", "й", "к", "л", "м", "н", "о", "п", "р", "с", "т", "у", "ф", "х", "ц", "ч", "ш", "ъ", "ь", "э", "я", "ю", "ў", "қ", "ғ", "ҳ",
-    "А", "Б", "Д", "Г", "Д", "Е", "Ё", "Ж", "З", "И", "Й", "К", "Л", "М", "Н", "О", "П", "Р", "С", "Т", "У", "Ф", "Х", "Ц", "Ч", "Ш", "Ъ", "Ь", "Э", "Ю", "Я", "Ў", "Қ", "Ғ", "Ҳ"
+    "Ê", "A", "B", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S", "T", "U", "V", "X", "Y", "Z", "Ŏ", "Ğ", "Š", "Č"
 ];
 
+/// Cyrillic letters in the official Uzbek alphabet order.
+///
+/// The letters ў, қ, ғ and ҳ follow я, as in the 1940 alphabet
+/// and the dictionaries that are still printed with it.
+const CYRILLIC_ORDER: [&str; 70] = [
+    "а", "б", "в", "г", "д", "е", "ё", "ж", "з", "и", "й", "к", "л", "м", "н", "о", "п", "р", "с", "т", "у", "ф", "х", "ц", "ч", "ш", "ъ", "ь", "э", "ю", "я", "ў", "қ", "ғ", "ҳ",
+    "А", "Б", "В", "Г", "Д", "Е", "Ё", "Ж", "З", "И", "Й", "К", "Л", "М", "Н", "О", "П", "Р", "С", "Т", "У", "Ф", "Х", "Ц", "Ч", "Ш", "Ъ", "Ь", "Э", "Ю", "Я", "Ў", "Қ", "Ғ", "Ҳ"
+];
+
+/// Weight of the first letter, placed after the ten digits.
+const LETTER_OFFSET: usize = 10;
+
+/// Weight of letters outside the Uzbek alphabet, placed after all Uzbek letters.
+const FOREIGN_OFFSET: usize = LETTER_OFFSET + LATIN_ORDER.len() + CYRILLIC_ORDER.len();
+
 const TO_SORT: [&str; 11] = [
     ("G[ʻʼ'‘’‛′ʽ`] Ğ"),
     ("g[ʻʼ'‘’‛′ʽ`] ğ"),
@@ -56,6 +139,276 @@ pub fn sort(text: &str) -> String {
     from_sortable(sorted_intermediate)
 }
 
+/// Sorts words with the multi-level collation.
+///
+/// Given String of text returns a new String with words sorted and separated with a newline.
+/// Unlike [`sort`], words are returned exactly as they were written.
+/// Words that are equal at the chosen strength keep their input order.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::alphabetic::{self, SortOptions, Strength};
+///
+/// let options = SortOptions { strength: Strength::Primary, ..Default::default() };
+/// let output = alphabetic::sort_with("olma G‘ozal Olma chiroyli o'zbek", &options);
+/// let expected = "olma\nOlma\no'zbek\nG‘ozal\nchiroyli\n".to_string();
+/// assert_eq!(output, expected);
+///```
+pub fn sort_with(text: &str, options: &SortOptions) -> String {
+    let entries = text.split_whitespace().map(|word| (word.to_string(), word)).collect();
+
+    sort_entries(entries, options)
+}
+
+/// Sorts whole lines with the multi-level collation.
+///
+/// Given String of text returns a new String with its lines sorted,
+/// so multi-word entries stay together. Blank lines are dropped.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::alphabetic::{self, SortOptions};
+///
+/// let input = "Navoiy asarlari\nAbdulla Qodiriy\nAbdulla Oripov\n";
+/// let output = alphabetic::sort_lines(input, &SortOptions::default());
+/// let expected = "Abdulla Oripov\nAbdulla Qodiriy\nNavoiy asarlari\n".to_string();
+/// assert_eq!(output, expected);
+///```
+pub fn sort_lines(text: &str, options: &SortOptions) -> String {
+    let (header, lines) = split_header(text, options);
+    let entries = lines.map(|line| (line.to_string(), line)).collect();
+
+    header + &sort_entries(entries, options)
+}
+
+/// Sorts delimited records such as CSV or TSV lines by one column.
+///
+/// Given String of text returns a new String with its lines sorted
+/// by the field at zero-based `column`, leaving each record untouched.
+/// Fields may be quoted with double quotes to contain the delimiter.
+/// Records without such column are sorted as if the field was empty.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::alphabetic::{self, SortOptions};
+///
+/// let options = SortOptions { header: true, ..Default::default() };
+/// let input = "id,ism,shahar\n1,Shahlo,Buxoro\n2,Zafar,\"Toshkent, Chilonzor\"\n3,Olim,Andijon\n";
+/// let output = alphabetic::sort_records(input, ',', 1, &options);
+/// let expected = "id,ism,shahar\n3,Olim,Andijon\n2,Zafar,\"Toshkent, Chilonzor\"\n1,Shahlo,Buxoro\n".to_string();
+/// assert_eq!(output, expected);
+///```
+pub fn sort_records(text: &str, delimiter: char, column: usize, options: &SortOptions) -> String {
+    let (header, lines) = split_header(text, options);
+    let entries = lines.map(|line| (get_field(line, delimiter, column), line)).collect();
+
+    header + &sort_entries(entries, options)
+}
+
+/// Groups words under the headings of their first letter.
+///
+/// Given String of text returns groups of sorted words in alphabetical order,
+/// each with the letter heading it belongs to. O‘, G‘, Sh and Ch
+/// are separate letters, so "Shahlo" is listed under "Sh" and not "S".
+/// The ng digraph is sorted as "n" and "g", so words like "ngoq" are listed
+/// under "N" next to the words they are sorted with.
+/// Words starting with a digit or a symbol are gathered under "#".
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::alphabetic;
+///
+/// let output = alphabetic::group_by_initial("Shahlo Sardor o‘rik olma Ўктам Шоҳида");
+/// let expected = vec![
+///     ("O".to_string(), vec!["olma".to_string()]),
+///     ("S".to_string(), vec!["Sardor".to_string()]),
+///     ("O‘".to_string(), vec!["o‘rik".to_string()]),
+///     ("Sh".to_string(), vec!["Shahlo".to_string()]),
+///     ("Ш".to_string(), vec!["Шоҳида".to_string()]),
+///     ("Ў".to_string(), vec!["Ўктам".to_string()]),
+/// ];
+/// assert_eq!(output, expected);
+///```
+pub fn group_by_initial(text: &str) -> Vec<(String, Vec<String>)> {
+    let options = SortOptions::default();
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+
+    for word in sort_with(text, &options).lines() {
+        let heading = get_initial(word);
+
+        match groups.last_mut() {
+            Some(group) if group.0 == heading => group.1.push(word.to_string()),
+            _ => groups.push((heading, vec![word.to_string()])),
+        }
+    }
+
+    groups
+}
+
+fn get_initial(word: &str) -> String {
+    let sortable = to_sortable(word.to_string());
+    let first = sortable.chars().map(fold_case).find(|&value| get_value(value).is_some());
+
+    let letter = match first {
+        Some(value) if !value.is_ascii_digit() => from_sortable(value.to_string()),
+        _ => return String::from("#"),
+    };
+
+    let mut chars = letter.chars();
+    match chars.next() {
+        Some(value) => value.to_uppercase().chain(chars).collect(),
+        None => String::from("#"),
+    }
+}
+
+fn split_header<'a>(text: &'a str, options: &SortOptions) -> (String, impl Iterator<Item = &'a str>) {
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+    let mut header = String::new();
+
+    if options.header {
+        if let Some(line) = lines.next() {
+            header.push_str(line);
+            header.push('\n');
+        }
+    }
+
+    (header, lines)
+}
+
+fn get_field(line: &str, delimiter: char, column: usize) -> String {
+    let mut field = String::new();
+    let mut index = 0;
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(value) = chars.next() {
+        if value == '"' {
+            if quoted && chars.peek() == Some(&'"') {
+                chars.next();
+                if index == column { field.push('"'); }
+            } else {
+                quoted = !quoted;
+            }
+        } else if value == delimiter && !quoted {
+            if index == column { break; }
+            index += 1;
+        } else if index == column {
+            field.push(value);
+        }
+    }
+
+    field.trim().to_string()
+}
+
+fn sort_entries(entries: Vec<(String, &str)>, options: &SortOptions) -> String {
+    let mut sortable: Vec<(SortKey, &str)> = entries
+        .into_iter()
+        .map(|(key, entry)| (sort_key(&key, options), entry))
+        .collect();
+    sortable.sort_by(|a, b| order(&a.0, &b.0, options));
+
+    if options.dedup {
+        sortable.dedup_by(|a, b| a.0 == b.0);
+    }
+
+    let mut result = String::new();
+    for (_, entry) in sortable {
+        result.push_str(entry);
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Compares two words with the multi-level collation.
+///
+/// The result is reversed when [`SortOptions::descending`] is set,
+/// [`SortOptions::dedup`] has no effect here.
+///
+/// # Example
+/// ```rust
+/// use std::cmp::Ordering;
+/// use korrektor::uzbek::alphabetic::{self, SortOptions, Strength};
+///
+/// let primary = SortOptions { strength: Strength::Primary, ..Default::default() };
+/// assert_eq!(alphabetic::compare("olma", "Olma", &primary), Ordering::Equal);
+///
+/// let secondary = SortOptions { strength: Strength::Secondary, ..Default::default() };
+/// assert_eq!(alphabetic::compare("olma", "Olma", &secondary), Ordering::Less);
+/// assert_eq!(alphabetic::compare("o‘rik", "o'rik", &secondary), Ordering::Equal);
+///```
+pub fn compare(word1: &str, word2: &str, options: &SortOptions) -> Ordering {
+    order(&sort_key(word1, options), &sort_key(word2, options), options)
+}
+
+fn order(key1: &SortKey, key2: &SortKey, options: &SortOptions) -> Ordering {
+    if options.descending {
+        key2.cmp(key1)
+    } else {
+        key1.cmp(key2)
+    }
+}
+
+fn sort_key(word: &str, options: &SortOptions) -> SortKey {
+    let letters = if options.script_agnostic {
+        script::to_latin(word)
+    } else {
+        word.to_string()
+    };
+
+    let sortable = to_sortable(letters);
+    let mut key = SortKey {
+        primary: Vec::new(),
+        secondary: Vec::new(),
+        tertiary: String::new(),
+    };
+
+    let mut chars = sortable.chars().peekable();
+
+    while let Some(value) = chars.next() {
+        if options.natural && value.is_ascii_digit() {
+            let mut digits = value.to_string();
+            while let Some(&next) = chars.peek() {
+                if !next.is_ascii_digit() {
+                    break;
+                }
+                digits.push(next);
+                chars.next();
+            }
+
+            let significant = digits.trim_start_matches('0').to_string();
+            key.primary.push(Weight::Number(significant.len(), significant));
+            key.secondary.push(0);
+            continue;
+        }
+
+        let folded = fold_case(value);
+
+        if let Some(weight) = get_value(folded) {
+            key.primary.push(Weight::Char(weight));
+            key.secondary.push(if folded == value { 0 } else { 1 });
+        }
+    }
+
+    if options.strength < Strength::Secondary {
+        key.secondary.clear();
+    }
+
+    if options.strength == Strength::Tertiary {
+        key.tertiary = word.to_string();
+    }
+
+    key
+}
+
+fn fold_case(value: char) -> char {
+    match value {
+        'Ö' => 'š',
+        'Ü' => 'č',
+        _ => value.to_lowercase().next().unwrap_or(value),
+    }
+}
+
 fn to_sortable(text: String) -> String {
     let mut input: String = text;
 
@@ -85,71 +438,54 @@ fn from_sortable(text: String) -> String {
 }
 
 fn usort(string1: &str, string2: &str) -> i8 {
-    let length = std::cmp::min(string1.len() - 1, string2.len() - 1);
-
-    for i in 0..length {
-        let char1 = match string1.chars().nth(i) {
-            Some(char) => char,
-            None => panic!("Error in usort: no char at the index {i} in &str: {string1}")
-        };
-        let char2 = match string2.chars().nth(i) {
-            Some(char) => char,
-            None => panic!("Error in usort: no char at the index {i} in &str: {string2}")
-        };
-
-        let mut value1 = 0;
-        let mut value2 = 0;
-
-        if is_exceptioned(char1) {
-            value1 = get_exceptioned_value(char1);
-        } else {
-            value1 = match CHAR_ORDER.iter().position(|&r| r == char1.to_string()) {
-                Some(num) => num,
-                None => panic!("Error in usort: char {char1} is not found and can not be sorted")
-            }
-        }
+    let values1 = string1.chars().filter_map(get_value);
+    let values2 = string2.chars().filter_map(get_value);
 
-        if is_exceptioned(char2) {
-            value2 = get_exceptioned_value(char2);
-        } else {
-            value2 = match CHAR_ORDER.iter().position(|&r| r == char2.to_string()) {
-                Some(num) => num,
-                None => panic!("Error in usort: char {char2} is not found can not be sorted")
-            }
-        }
+    // words equal at the letter level are ordered by their punctuation
+    // and exact characters, so the result never depends on input order
+    let ordering = values1.cmp(values2).then_with(|| string1.cmp(string2));
 
-        match value1.cmp(&value2) {
-            std::cmp::Ordering::Less => return -1,
-            std::cmp::Ordering::Greater => return 1,
-            std::cmp::Ordering::Equal => continue,
-        };
-    }
-
-    match (string1.len()).cmp(&string2.len()) {
+    match ordering {
         std::cmp::Ordering::Less => -1,
         std::cmp::Ordering::Greater => 1,
         std::cmp::Ordering::Equal => 0,
     }
 }
 
+/// Returns the primary weight of a char.
+///
+/// Digits come first, then Uzbek latin and cyrillic letters,
+/// then any other letters by their code point.
+/// Punctuation, apostrophes and symbols are ignored and give `None`.
+fn get_value(value: char) -> Option<usize> {
+    if let Some(digit) = value.to_digit(10) {
+        return Some(digit as usize);
+    }
+
+    if is_exceptioned(value) {
+        return Some(LETTER_OFFSET + get_exceptioned_value(value));
+    }
+
+    let letter = value.to_string();
+
+    if let Some(num) = LATIN_ORDER.iter().position(|&r| r == letter) {
+        return Some(LETTER_OFFSET + num);
+    }
+
+    if let Some(num) = CYRILLIC_ORDER.iter().position(|&r| r == letter) {
+        return Some(LETTER_OFFSET + LATIN_ORDER.len() + num);
+    }
+
+    if value.is_alphanumeric() && !letter::is_apostrophe(value) {
+        return Some(FOREIGN_OFFSET + value as usize);
+    }
+
+    None
+}
+
 fn sort_sortable(text: &str) -> String {
     let mut sortable: Vec<&str> = text.split_whitespace().collect();
-    let mut len = sortable.len();
-
-    let mut sorted = false;
-    while !sorted {
-        sorted = true;
-        for i in 0..len - 1 {
-            match usort(sortable[i], sortable[i + 1]) {
-                1 => {
-                    sortable.swap(i, i + 1);
-                    sorted = false;
-                }
-                _ => continue,
-            }
-        }
-        len -= 1;
-    }
+    sortable.sort_by(|a, b| usort(a, b).cmp(&0));
 
     let mut result = String::new();
     for word in sortable {
@@ -166,11 +502,15 @@ fn is_exceptioned(value: char) -> bool {
     false
 }
 
+/// Returns the position of the capital "Š" or "Č" for the placeholders of uppercase "SH" and "CH".
 fn get_exceptioned_value(value: char) -> usize {
-    if value == 'Ö' { return 55; }
-    if value == 'Ü' { return 56; }
+    let letter = match value {
+        'Ö' => "Š",
+        'Ü' => "Č",
+        _ => return 0,
+    };
 
-    0
+    LATIN_ORDER.iter().position(|&r| r == letter).unwrap_or(0)
 }
 
 #[cfg(test)]
@@ -197,8 +537,8 @@ mod as_tests {
 
     #[test]
     fn get_exceptioned_value_test() {
-        assert_eq!(get_exceptioned_value('Ö'), 55);
-        assert_eq!(get_exceptioned_value('Ü'), 56);
+        assert_eq!(get_exceptioned_value('Ö'), 56);
+        assert_eq!(get_exceptioned_value('Ü'), 57);
     }
 
     #[test]
@@ -208,6 +548,236 @@ mod as_tests {
         assert_eq!(usort("čilonzor", "čiroyli"), -1);
     }
 
+    #[test]
+    fn get_value_test() {
+        assert_eq!(get_value('0'), Some(0));
+        assert_eq!(get_value('9'), Some(9));
+        assert_eq!(get_value('a'), Some(11));
+        assert_eq!(get_value('Č'), Some(67));
+        assert_eq!(get_value('Ö'), get_value('Š'));
+        assert_eq!(get_value('Ü'), get_value('Č'));
+        assert_eq!(get_value('а'), Some(68));
+        assert_eq!(get_value('в'), Some(70));
+        assert!(get_value('г') < get_value('д'));
+        assert!(get_value('я') < get_value('ў'));
+        assert!(get_value('ю') < get_value('я'));
+        assert!(get_value('Ҳ') < get_value('ñ'));
+        assert!(get_value('Ҳ') < get_value('щ'));
+        assert_eq!(get_value(','), None);
+        assert_eq!(get_value('ʼ'), None);
+        assert_eq!(get_value('-'), None);
+    }
+
+    #[test]
+    fn usort_cyrillic_test() {
+        assert_eq!(usort("ватан", "вақт"), -1);
+        assert_eq!(usort("гул", "дарё"), -1);
+        assert_eq!(usort("қалам", "ғалаба"), -1);
+        assert_eq!(usort("ҳаво", "ҳаво"), 0);
+    }
+
+    #[test]
+    fn usort_ignorable_test() {
+        assert_eq!(usort("salom,", "salom"), 1);
+        assert_eq!(usort("maʼno", "mano"), 1);
+        assert_eq!(usort("maʼno", "maqsad"), -1);
+    }
+
+    #[test]
+    fn cyrillic_dictionary_order_test() {
+        // Headwords in the order of "Ўзбек тилининг изоҳли луғати".
+        let expected = [
+            "абадий", "бахт", "ватан", "вақт", "гул", "дарё", "ер", "ёз", "жон", "зар", "иш", "йўл",
+            "кун", "лола", "маъно", "меҳр", "нон", "ой", "пахта", "рўмол", "сув", "тоғ", "узум", "фасл",
+            "хат", "цирк", "чой", "шамол", "эрта", "юлдуз", "янги", "ўрик", "қалам", "ғалаба", "ҳаво",
+        ];
+        let mut input = expected;
+        input.reverse();
+
+        let output = sort(&input.join(" "));
+        assert_eq!(output, expected.join("\n") + "\n");
+    }
+
+    #[test]
+    fn sort_unknown_chars_test() {
+        let input = "Jose, ñandu щука 2-sinf maʼno 10 olma. xat";
+        let output = "10\n2-sinf\nmaʼno\nolma.\nxat\nJose,\nñandu\nщука\n";
+        assert_eq!(sort(input), output);
+        assert_eq!(sort("SHAM G‘OZ"), "G‘OZ\nSHAM\n");
+        assert_eq!(sort("CHOY SHAM O‘T"), "O‘T\nSHAM\nCHOY\n");
+    }
+
+    #[test]
+    fn sort_empty_test() {
+        assert_eq!(sort(""), "");
+        assert_eq!(sort(" , "), ",\n");
+    }
+
+    #[test]
+    fn fold_case_test() {
+        assert_eq!(fold_case('Ö'), 'š');
+        assert_eq!(fold_case('Ü'), 'č');
+        assert_eq!(fold_case('Ŏ'), 'ŏ');
+        assert_eq!(fold_case('Ҳ'), 'ҳ');
+        assert_eq!(fold_case('5'), '5');
+    }
+
+    #[test]
+    fn compare_strength_test() {
+        let primary = SortOptions { strength: Strength::Primary, ..Default::default() };
+        let secondary = SortOptions { strength: Strength::Secondary, ..Default::default() };
+        let tertiary = SortOptions::default();
+
+        assert_eq!(compare("olma", "Olma", &primary), Ordering::Equal);
+        assert_eq!(compare("olma", "Olma", &secondary), Ordering::Less);
+        assert_eq!(compare("SHAHAR", "Shahar", &primary), Ordering::Equal);
+        assert_eq!(compare("o‘rik", "o'rik", &primary), Ordering::Equal);
+        assert_eq!(compare("o‘rik", "o'rik", &secondary), Ordering::Equal);
+        assert_ne!(compare("o‘rik", "o'rik", &tertiary), Ordering::Equal);
+        assert_eq!(compare("salom,", "salom", &secondary), Ordering::Equal);
+        assert_eq!(compare("salom,", "salom", &tertiary), Ordering::Greater);
+        assert_eq!(compare("Zafar", "alisher", &tertiary), Ordering::Greater);
+    }
+
+    #[test]
+    fn sort_with_test() {
+        let primary = SortOptions { strength: Strength::Primary, ..Default::default() };
+        let input = "Sherzod olma shahar Olma o‘rik o'rik Ozod";
+        let output = "olma\nOlma\nOzod\no‘rik\no'rik\nshahar\nSherzod\n";
+        assert_eq!(sort_with(input, &primary), output);
+
+        let tertiary = SortOptions::default();
+        let output = "olma\nOlma\nOzod\no'rik\no‘rik\nshahar\nSherzod\n";
+        assert_eq!(sort_with(input, &tertiary), output);
+    }
+
+    #[test]
+    fn script_agnostic_test() {
+        let options = SortOptions { script_agnostic: true, ..Default::default() };
+        assert_eq!(compare("Алишер", "Zafar", &options), Ordering::Less);
+        assert_eq!(compare("Alisher", "Алишер", &options), Ordering::Less);
+
+        let primary = SortOptions { strength: Strength::Primary, script_agnostic: true, ..Default::default() };
+        assert_eq!(compare("Alisher", "Алишер", &primary), Ordering::Equal);
+        assert_eq!(compare("Ўктам", "O‘ktam", &primary), Ordering::Equal);
+
+        let input = "Zafar Алишер Bobur Шаҳло Alisher Sardor";
+        let output = "Alisher\nАлишер\nBobur\nSardor\nZafar\nШаҳло\n";
+        assert_eq!(sort_with(input, &options), output);
+    }
+
+    #[test]
+    fn natural_test() {
+        let options = SortOptions { natural: true, ..Default::default() };
+        assert_eq!(compare("2-sinf", "10-sinf", &options), Ordering::Less);
+        assert_eq!(compare("uy 9", "uy 10", &options), Ordering::Less);
+        assert_eq!(compare("007", "7", &options), Ordering::Less);
+        assert_eq!(compare("10-sinf", "abituriyent", &options), Ordering::Less);
+
+        let plain = SortOptions::default();
+        assert_eq!(compare("2-sinf", "10-sinf", &plain), Ordering::Greater);
+
+        let input = "10-sinf 2-sinf 1-sinf 11-a 2-b";
+        let output = "1-sinf\n2-b\n2-sinf\n10-sinf\n11-a\n";
+        assert_eq!(sort_with(input, &options), output);
+    }
+
+    #[test]
+    fn descending_test() {
+        let options = SortOptions { descending: true, natural: true, ..Default::default() };
+        assert_eq!(compare("2-sinf", "10-sinf", &options), Ordering::Greater);
+
+        let input = "olma 2-sinf shaftoli 10-sinf anor";
+        let output = "shaftoli\nolma\nanor\n10-sinf\n2-sinf\n";
+        assert_eq!(sort_with(input, &options), output);
+    }
+
+    #[test]
+    fn dedup_test() {
+        let options = SortOptions { dedup: true, ..Default::default() };
+        assert_eq!(sort_with("olma nok olma Olma", &options), "nok\nolma\nOlma\n");
+
+        let primary = SortOptions { strength: Strength::Primary, dedup: true, ..Default::default() };
+        assert_eq!(sort_with("Olma nok olma o'rik o‘rik", &primary), "nok\nOlma\no'rik\n");
+
+        let descending = SortOptions { strength: Strength::Primary, dedup: true, descending: true, ..Default::default() };
+        assert_eq!(sort_with("Olma nok olma o'rik o‘rik", &descending), "o'rik\nOlma\nnok\n");
+    }
+
+    #[test]
+    fn get_field_test() {
+        assert_eq!(get_field("1,Shahlo,Buxoro", ',', 1), "Shahlo");
+        assert_eq!(get_field("1,\"Qodiriy, Abdulla\",Toshkent", ',', 1), "Qodiriy, Abdulla");
+        assert_eq!(get_field("1,\"Qodiriy, Abdulla\",Toshkent", ',', 2), "Toshkent");
+        assert_eq!(get_field("\"\"\"O‘tkan kunlar\"\" romani\"", ',', 0), "\"O‘tkan kunlar\" romani");
+        assert_eq!(get_field("1\tAbdulla Qodiriy\t1894", '\t', 2), "1894");
+        assert_eq!(get_field("1,Shahlo", ',', 5), "");
+    }
+
+    #[test]
+    fn sort_lines_test() {
+        let input = "Abdulla Qodiriy\n\nAbdulla Oripov\nalisher Navoiy\nCho‘lpon\n";
+        let output = "Abdulla Oripov\nAbdulla Qodiriy\nalisher Navoiy\nCho‘lpon\n";
+        let options = SortOptions { strength: Strength::Primary, ..Default::default() };
+        assert_eq!(sort_lines(input, &options), output);
+
+        let options = SortOptions { header: true, descending: true, ..Default::default() };
+        let input = "Muallif\nAbdulla Qodiriy\nCho‘lpon\nAbdulla Oripov";
+        let output = "Muallif\nCho‘lpon\nAbdulla Qodiriy\nAbdulla Oripov\n";
+        assert_eq!(sort_lines(input, &options), output);
+    }
+
+    #[test]
+    fn sort_records_test() {
+        let options = SortOptions { natural: true, ..Default::default() };
+        let input = "Karimov\t10-sinf\r\nAliyeva\t2-sinf\r\nShokirov\t9-sinf\r\nBo‘riyev\n";
+        let output = "Bo‘riyev\nAliyeva\t2-sinf\nShokirov\t9-sinf\nKarimov\t10-sinf\n";
+        assert_eq!(sort_records(input, '\t', 1, &options), output);
+
+        let options = SortOptions { header: true, dedup: true, ..Default::default() };
+        let input = "ism;shahar\nOlim;Andijon\nShahlo;Buxoro\nOlim;Nukus\n";
+        let output = "ism;shahar\nOlim;Andijon\nShahlo;Buxoro\n";
+        assert_eq!(sort_records(input, ';', 0, &options), output);
+    }
+
+    #[test]
+    fn get_initial_test() {
+        assert_eq!(get_initial("Shahlo"), "Sh");
+        assert_eq!(get_initial("SHAHLO"), "Sh");
+        assert_eq!(get_initial("sardor"), "S");
+        assert_eq!(get_initial("chiroyli"), "Ch");
+        assert_eq!(get_initial("o'rik"), "O‘");
+        assert_eq!(get_initial("G‘ozal"), "G‘");
+        assert_eq!(get_initial("«Ona»"), "O");
+        assert_eq!(get_initial("ғалаба"), "Ғ");
+        assert_eq!(get_initial("2-sinf"), "#");
+        assert_eq!(get_initial("—"), "#");
+    }
+
+    #[test]
+    fn group_by_initial_test() {
+        let output = group_by_initial("Shahlo g‘isht sabzi Chamon chiroyli 5-uy Gulnora шамол чой Алишер");
+        let expected = vec![
+            ("#".to_string(), vec!["5-uy".to_string()]),
+            ("G".to_string(), vec!["Gulnora".to_string()]),
+            ("S".to_string(), vec!["sabzi".to_string()]),
+            ("G‘".to_string(), vec!["g‘isht".to_string()]),
+            ("Sh".to_string(), vec!["Shahlo".to_string()]),
+            ("Ch".to_string(), vec!["Chamon".to_string(), "chiroyli".to_string()]),
+            ("А".to_string(), vec!["Алишер".to_string()]),
+            ("Ч".to_string(), vec!["чой".to_string()]),
+            ("Ш".to_string(), vec!["шамол".to_string()]),
+        ];
+        assert_eq!(output, expected);
+        assert!(group_by_initial("").is_empty());
+
+        let output = group_by_initial("non ngoq Ng nafas");
+        let expected = vec![
+            ("N".to_string(), vec!["nafas".to_string(), "Ng".to_string(), "ngoq".to_string(), "non".to_string()]),
+        ];
+        assert_eq!(output, expected);
+    }
+
     #[test]
     fn get_sorted_text_test() {
         let input = "G‘ozal estafeta chilonzor o'zbek chiroyli";
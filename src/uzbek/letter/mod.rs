@@ -0,0 +1,253 @@
+//! Iterators over Uzbek letters of a word.
+//!
+//! Both latin and cyrillic modes are supported.
+
+/// Apostrophe variants used for o‘, g‘ and the tutuq belgisi.
+const APOSTROPHES: &str = "ʻʼ'‘’‛′ʽ`";
+
+const LATIN_VOWELS: &str = "aeiou";
+
+/// Vowels of both alphabets, lowercased with the apostrophe of o‘ unified.
+const VOWELS: [&str; 16] = [
+    "a", "e", "i", "o", "u", "o‘",
+    "а", "о", "у", "э", "и", "я", "ё", "ю", "е", "ў",
+];
+
+/// Word beginnings where "ng" before a vowel is a single letter, as in "de-ngiz".
+///
+/// In other words it is read as "n" and "g", as in "kun-ga".
+const NG_STEMS: [&str; 19] = [
+    "deng", "sing", "yeng", "yang", "tong", "keng", "teng", "ming", "rang", "jang",
+    "ko‘ng", "ong", "ing", "chang", "tang", "zang", "bong", "mung", "ding",
+];
+
+/// An iterator over the letters of a word and their byte positions.
+///
+/// Created by [`letter_indices`].
+#[derive(Debug, Clone)]
+pub struct LetterIndices<'a> {
+    word: &'a str,
+    position: usize,
+}
+
+/// An iterator over the letters of a word.
+///
+/// Created by [`letters`].
+#[derive(Debug, Clone)]
+pub struct Letters<'a> {
+    inner: LetterIndices<'a>,
+}
+
+/// Returns an iterator over Uzbek letters of a word.
+///
+/// O‘, G‘, Sh, Ch and Ng are yielded as single letters with any apostrophe
+/// variant, and the tutuq belgisi between two letters is a letter of its own,
+/// so "s'h" in "Is'hoq" gives three letters. "ng" followed by a vowel is only
+/// a single letter after known stems such as "deng" in "dengiz".
+/// Chars which are not letters, like punctuation and digits, are skipped.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek;
+///
+/// let output: Vec<&str> = uzbek::letters("O‘zbekiston").collect();
+/// assert_eq!(output, vec!["O‘", "z", "b", "e", "k", "i", "s", "t", "o", "n"]);
+///
+/// assert_eq!(uzbek::letters("choyshab").count(), 6);
+/// assert_eq!(uzbek::letters("ma'no").count(), 5);
+/// ```
+pub fn letters(word: &str) -> Letters<'_> {
+    Letters {
+        inner: letter_indices(word),
+    }
+}
+
+/// Returns an iterator over Uzbek letters of a word and their byte positions.
+///
+/// Letters are split the same way as in [`letters`].
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek;
+///
+/// let output: Vec<(usize, &str)> = uzbek::letter_indices("bog‘cha").collect();
+/// assert_eq!(output, vec![(0, "b"), (1, "o"), (2, "g‘"), (6, "ch"), (8, "a")]);
+/// ```
+pub fn letter_indices(word: &str) -> LetterIndices<'_> {
+    LetterIndices { word, position: 0 }
+}
+
+impl<'a> Iterator for LetterIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.position;
+            let first = self.word[start..].chars().next()?;
+            let length = letter_length(self.word, start);
+
+            if length == 0 {
+                self.position += first.len_utf8();
+                continue;
+            }
+
+            self.position += length;
+            return Some((start, &self.word[start..start + length]));
+        }
+    }
+}
+
+impl<'a> Iterator for Letters<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, letter)| letter)
+    }
+}
+
+/// Returns byte length of the letter starting at `start`, or 0 if there is no letter.
+fn letter_length(word: &str, start: usize) -> usize {
+    let mut chars = word[start..].chars();
+    let first = match chars.next() {
+        Some(value) => value,
+        None => return 0,
+    };
+    let second = chars.next();
+    let third = chars.next();
+
+    if is_apostrophe(first) {
+        let mut before = word[..start].chars().rev();
+        let previous = match before.next() {
+            // apostrophe of o‘ or g‘
+            Some(value) if is_apostrophe(value) => before.next().filter(|p| "oOgG".contains(*p)),
+            value => value,
+        };
+        let between_letters = previous.is_some_and(char::is_alphabetic)
+            && second.is_some_and(char::is_alphabetic);
+
+        return if between_letters { first.len_utf8() } else { 0 };
+    }
+
+    if !first.is_alphabetic() {
+        return 0;
+    }
+
+    let lower = first.to_ascii_lowercase();
+    let next = second.map(|value| value.to_ascii_lowercase());
+
+    match (lower, next) {
+        ('o' | 'g', Some(value)) if is_apostrophe(value) => first.len_utf8() + value.len_utf8(),
+        ('s' | 'c', Some('h')) => 2,
+        ('n', Some('g')) if !third.is_some_and(is_apostrophe) && is_ng(word, start, third) => 2,
+        _ => first.len_utf8(),
+    }
+}
+
+fn is_ng(word: &str, start: usize, after: Option<char>) -> bool {
+    let vowel_follows = after.is_some_and(|value| LATIN_VOWELS.contains(value.to_ascii_lowercase()));
+
+    if !vowel_follows {
+        return true;
+    }
+
+    // possessive "-ingiz" and "-ngiz", as in "kitobingiz"
+    if normalize(&word[start + 2..]).starts_with("iz") {
+        return true;
+    }
+
+    let prefix = normalize(&word[..start + 2]);
+    NG_STEMS.contains(&prefix.as_str())
+}
+
+/// Lowercases the text and unifies apostrophe variants to ‘.
+pub(crate) fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|value| if is_apostrophe(value) { '‘' } else { value })
+        .collect()
+}
+
+pub(crate) fn is_apostrophe(value: char) -> bool {
+    APOSTROPHES.contains(value)
+}
+
+/// Checks whether a letter of either alphabet is a vowel, ignoring case and apostrophe variants.
+pub(crate) fn is_vowel(value: &str) -> bool {
+    VOWELS.contains(&value) || VOWELS.contains(&normalize(value).as_str())
+}
+
+/// Checks whether a char belongs to the cyrillic block.
+pub(crate) fn is_cyrillic(value: char) -> bool {
+    ('\u{400}'..='\u{4FF}').contains(&value)
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    fn collect(word: &str) -> Vec<&str> {
+        letters(word).collect()
+    }
+
+    #[test]
+    fn digraphs_test() {
+        assert_eq!(collect("o‘zbek"), vec!["o‘", "z", "b", "e", "k"]);
+        assert_eq!(collect("G'ozal"), vec!["G'", "o", "z", "a", "l"]);
+        assert_eq!(collect("Shahlo"), vec!["Sh", "a", "h", "l", "o"]);
+        assert_eq!(collect("CHOY"), vec!["CH", "O", "Y"]);
+        assert_eq!(collect("qo‘ng‘iroq"), vec!["q", "o‘", "n", "g‘", "i", "r", "o", "q"]);
+    }
+
+    #[test]
+    fn tutuq_test() {
+        assert_eq!(collect("ma'no"), vec!["m", "a", "'", "n", "o"]);
+        assert_eq!(collect("Is'hoq"), vec!["I", "s", "'", "h", "o", "q"]);
+        assert_eq!(collect("'salom'"), vec!["s", "a", "l", "o", "m"]);
+        assert_eq!(collect("mo‘'jiza"), vec!["m", "o‘", "'", "j", "i", "z", "a"]);
+    }
+
+    #[test]
+    fn ng_test() {
+        assert_eq!(collect("tong"), vec!["t", "o", "ng"]);
+        assert_eq!(collect("tongda"), vec!["t", "o", "ng", "d", "a"]);
+        assert_eq!(collect("dengiz"), vec!["d", "e", "ng", "i", "z"]);
+        assert_eq!(collect("Yangi"), vec!["Y", "a", "ng", "i"]);
+        assert_eq!(collect("ko‘ngil"), vec!["k", "o‘", "ng", "i", "l"]);
+        assert_eq!(collect("kunga"), vec!["k", "u", "n", "g", "a"]);
+        assert_eq!(collect("kitobingiz"), vec!["k", "i", "t", "o", "b", "i", "ng", "i", "z"]);
+    }
+
+    #[test]
+    fn other_chars_test() {
+        assert_eq!(collect("чиройли"), vec!["ч", "и", "р", "о", "й", "л", "и"]);
+        assert_eq!(collect("маъно"), vec!["м", "а", "ъ", "н", "о"]);
+        assert_eq!(collect("kitob-ku,"), vec!["k", "i", "t", "o", "b", "k", "u"]);
+        assert_eq!(collect("5-sinf"), vec!["s", "i", "n", "f"]);
+        assert_eq!(collect(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn letter_indices_test() {
+        let output: Vec<(usize, &str)> = letter_indices("«o‘g‘il»").collect();
+        assert_eq!(output, vec![(2, "o‘"), (6, "g‘"), (10, "i"), (11, "l")]);
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(normalize("Ko'ngil"), "ko‘ngil");
+        assert_eq!(normalize("O`G‘IL"), "o‘g‘il");
+    }
+
+    #[test]
+    fn is_vowel_test() {
+        assert!(is_vowel("o‘"));
+        assert!(is_vowel("O'"));
+        assert!(is_vowel("Ў"));
+        assert!(is_vowel("е"));
+        assert!(!is_vowel("g‘"));
+        assert!(!is_vowel("sh"));
+        assert!(!is_vowel("қ"));
+        assert!(is_cyrillic('ғ'));
+        assert!(!is_cyrillic('g'));
+    }
+}
@@ -1,4 +1,16 @@
 //! Functionality with Uzbek-specific implementations.
 pub mod  alphabetic;
 pub mod number;
-pub mod tokenize;
\ No newline at end of file
+pub mod tokenize;
+pub mod readability;
+pub mod poetry;
+pub mod morphology;
+pub mod tagger;
+pub mod stopwords;
+pub(crate) mod script;
+pub(crate) mod letter;
+mod stem;
+
+pub use letter::{letters, letter_indices, Letters, LetterIndices};
+pub use stem::stem;
+pub use script::Script;
\ No newline at end of file
@@ -0,0 +1,119 @@
+#![allow(unused)]
+
+use super::PartOfSpeech::{self, *};
+
+/// Lemmas of the bundled lexicon with their parts of speech.
+///
+/// A lemma with several parts of speech is listed once for each of them.
+pub(super) const LEXICON: [(&str, PartOfSpeech); 378] = [
+    // nouns
+    ("aka", Noun), ("amaki", Noun), ("avtobus", Noun), ("bahor", Noun), ("bayram", Noun),
+    ("bayroq", Noun), ("bilak", Noun), ("bilim", Noun), ("bog‘", Noun), ("bola", Noun),
+    ("bosh", Noun), ("bo‘yin", Noun), ("buloq", Noun), ("burun", Noun), ("chelak", Noun),
+    ("choy", Noun), ("dala", Noun), ("daraxt", Noun), ("dars", Noun), ("daftar", Noun),
+    ("daryo", Noun), ("dengiz", Noun), ("deraza", Noun), ("do‘st", Noun), ("dunyo", Noun),
+    ("eshik", Noun), ("fikr", Noun), ("gap", Noun), ("gul", Noun), ("hafta", Noun),
+    ("hayot", Noun), ("havo", Noun), ("ilm", Noun), ("inson", Noun), ("ish", Noun),
+    ("ism", Noun), ("kecha", Noun), ("kitob", Noun), ("kiyik", Noun), ("kun", Noun),
+    ("ko‘cha", Noun), ("ko‘ngil", Noun), ("ko‘z", Noun), ("kuz", Noun), ("maktab", Noun),
+    ("mashina", Noun), ("mehmon", Noun), ("non", Noun), ("ot", Noun), ("ota", Noun),
+    ("oila", Noun), ("oy", Noun), ("olma", Noun), ("ona", Noun), ("opa", Noun),
+    ("odam", Noun), ("og‘iz", Noun), ("o‘g‘il", Noun), ("o‘qituvchi", Noun), ("o‘rtoq", Noun),
+    ("o‘yinchoq", Noun), ("qalam", Noun), ("qishloq", Noun), ("qish", Noun), ("qiz", Noun),
+    ("qorin", Noun), ("qoshiq", Noun), ("qo‘l", Noun), ("qo‘shiq", Noun), ("quloq", Noun),
+    ("raqam", Noun), ("savol", Noun), ("shahar", Noun), ("she’r", Noun), ("singil", Noun),
+    ("soat", Noun), ("stol", Noun), ("suv", Noun), ("so‘z", Noun), ("talaba", Noun),
+    ("tarmoq", Noun), ("tayoq", Noun), ("terak", Noun), ("til", Noun), ("tilak", Noun),
+    ("tog‘", Noun), ("tuproq", Noun), ("uka", Noun), ("uy", Noun), ("vaqt", Noun),
+    ("vatan", Noun), ("xalq", Noun), ("xat", Noun), ("xona", Noun), ("yil", Noun),
+    ("yo‘l", Noun), ("yoz", Noun), ("yurak", Noun), ("yurt", Noun), ("javob", Noun),
+    ("bozor", Noun), ("do‘kon", Noun), ("pul", Noun), ("kasal", Noun), ("shifokor", Noun),
+    ("ta’lim", Noun), ("tarix", Noun), ("musiqa", Noun), ("sport", Noun), ("o‘yin", Noun),
+    ("qor", Noun), ("yomg‘ir", Noun), ("quyosh", Noun), ("osmon", Noun), ("yer", Noun),
+    ("tong", Noun), ("kechqurun", Noun), ("ertalab", Noun), ("tush", Noun), ("ovqat", Noun),
+    ("bag‘ir", Noun), ("egin", Noun), ("ko‘kil", Noun), ("qayin", Noun), ("ko‘mir", Noun),
+    // proper nouns
+    ("Andijon", Propn), ("Buxoro", Propn), ("Farg‘ona", Propn), ("Namangan", Propn), ("Navoiy", Propn),
+    ("O‘zbekiston", Propn), ("Qo‘qon", Propn), ("Samarqand", Propn), ("Toshkent", Propn), ("Xiva", Propn),
+    ("Termiz", Propn), ("Nukus", Propn), ("Qarshi", Propn), ("Jizzax", Propn), ("Guliston", Propn),
+    // verbs
+    ("ayt", Verb), ("bil", Verb), ("boshla", Verb), ("ber", Verb), ("bor", Verb),
+    ("bo‘l", Verb), ("chiq", Verb), ("eshit", Verb), ("gapir", Verb), ("ich", Verb),
+    ("ishla", Verb), ("ket", Verb), ("kel", Verb), ("kir", Verb), ("ko‘r", Verb),
+    ("kul", Verb), ("kut", Verb), ("och", Verb), ("ol", Verb), ("ot", Verb),
+    ("o‘qi", Verb), ("o‘rgan", Verb), ("o‘tir", Verb), ("o‘yla", Verb), ("o‘yna", Verb),
+    ("pishir", Verb), ("qara", Verb), ("qil", Verb), ("qo‘y", Verb), ("sakra", Verb),
+    ("sev", Verb), ("sot", Verb), ("so‘zla", Verb), ("top", Verb), ("tugat", Verb),
+    ("tur", Verb), ("tush", Verb), ("tushun", Verb), ("uxla", Verb), ("yasha", Verb),
+    ("ye", Verb), ("yig‘la", Verb), ("yop", Verb), ("yot", Verb), ("yoz", Verb),
+    ("yugur", Verb), ("yur", Verb), ("yuv", Verb), ("yubor", Verb), ("chiz", Verb),
+    ("sana", Verb), ("so‘ra", Verb), ("qayt", Verb), ("o‘t", Verb), ("to‘xta", Verb),
+    ("kiy", Verb), ("tanla", Verb), ("yaxshila", Verb), ("tayyorla", Verb),
+    // auxiliaries
+    ("edi", Aux), ("ekan", Aux), ("emish", Aux), ("emas", Aux), ("kerak", Aux),
+    // adjectives
+    ("achchiq", Adj), ("aqlli", Adj), ("baland", Adj), ("bor", Adj), ("chiroyli", Adj),
+    ("eski", Adj), ("go‘zal", Adj), ("issiq", Adj), ("katta", Adj), ("keng", Adj),
+    ("kichik", Adj), ("ko‘k", Adj), ("oq", Adj), ("past", Adj), ("qisqa", Adj),
+    ("qizil", Adj), ("qora", Adj), ("sariq", Adj), ("shirin", Adj), ("sovuq", Adj),
+    ("tor", Adj), ("uzun", Adj), ("yangi", Adj), ("yashil", Adj), ("yaxshi", Adj),
+    ("yomon", Adj), ("yo‘q", Adj), ("qiziq", Adj), ("muhim", Adj), ("oson", Adj),
+    ("qiyin", Adj), ("toza", Adj), ("kuchli", Adj), ("baxtli", Adj), ("mazali", Adj),
+    // adverbs
+    ("bugun", Adv), ("doim", Adv), ("endi", Adv), ("ertaga", Adv), ("hali", Adv),
+    ("hozir", Adv), ("juda", Adv), ("kecha", Adv), ("ko‘p", Adv), ("oz", Adv),
+    ("sekin", Adv), ("tez", Adv), ("yana", Adv), ("hech", Adv), ("birga", Adv),
+    ("ancha", Adv), ("eng", Adv), ("tezda", Adv), ("keyin", Adv), ("oldin", Adv),
+    // pronouns
+    ("men", Pron), ("sen", Pron), ("u", Pron), ("biz", Pron), ("siz", Pron),
+    ("ular", Pron), ("o‘z", Pron), ("kim", Pron), ("nima", Pron), ("qayer", Pron),
+    ("hamma", Pron), ("hech", Pron), ("bular", Pron), ("shular", Pron),
+    // determiners
+    ("bu", Det), ("shu", Det), ("o‘sha", Det), ("u", Det), ("har", Det),
+    ("barcha", Det), ("ba’zi", Det), ("qaysi", Det), ("qanday", Det), ("hamma", Det),
+    // numerals
+    ("bir", Num), ("ikki", Num), ("uch", Num), ("to‘rt", Num), ("besh", Num),
+    ("olti", Num), ("yetti", Num), ("sakkiz", Num), ("to‘qqiz", Num), ("o‘n", Num),
+    ("yigirma", Num), ("o‘ttiz", Num), ("qirq", Num), ("ellik", Num), ("oltmish", Num),
+    ("yetmish", Num), ("sakson", Num), ("to‘qson", Num), ("yuz", Num), ("ming", Num),
+    ("million", Num), ("milliard", Num), ("nol", Num), ("necha", Num), ("qancha", Num),
+    // adpositions
+    ("bilan", Adp), ("uchun", Adp), ("haqida", Adp), ("bo‘yicha", Adp), ("kabi", Adp),
+    ("sari", Adp), ("tomon", Adp), ("orqali", Adp), ("qadar", Adp), ("keyin", Adp),
+    ("oldin", Adp), ("so‘ng", Adp), ("beri", Adp), ("tashqari", Adp), ("ko‘ra", Adp),
+    ("singari", Adp), ("bo‘ylab", Adp), ("uzra", Adp), ("ichida", Adp), ("ustida", Adp),
+    // conjunctions
+    ("va", Cconj), ("lekin", Cconj), ("ammo", Cconj), ("biroq", Cconj), ("yoki", Cconj),
+    ("hamda", Cconj), ("balki", Cconj), ("yo", Cconj), ("na", Cconj), ("goh", Cconj),
+    ("chunki", Sconj), ("agar", Sconj), ("garchi", Sconj), ("toki", Sconj), ("go‘yo", Sconj),
+    ("holbuki", Sconj), ("modomiki", Sconj), ("mabodo", Sconj), ("basharti", Sconj), ("ki", Sconj),
+    // particles
+    ("ham", Part), ("esa", Part), ("faqat", Part), ("xolos", Part), ("axir", Part),
+    ("hatto", Part), ("mi", Part), ("chi", Part), ("ku", Part), ("da", Part),
+    ("yu", Part), ("ya", Part), ("naqadar", Part), ("nahotki", Part), ("mana", Part),
+    ("ana", Part), ("deyarli", Part), ("aynan", Part), ("faqatgina", Part), ("xuddi", Part),
+    // interjections
+    ("voy", Intj), ("oh", Intj), ("ura", Intj), ("ha", Intj), ("salom", Intj),
+    ("rahmat", Intj), ("xayr", Intj), ("obbo", Intj), ("e", Intj), ("dod", Intj),
+];
+
+/// Nouns, which lose the vowel of their last syllable before a vowel suffix, with their bound stems.
+pub(super) const ELISION: [(&str, &str); 12] = [
+    ("og‘iz", "og‘z"),
+    ("burun", "burn"),
+    ("singil", "singl"),
+    ("o‘g‘il", "o‘g‘l"),
+    ("ko‘ngil", "ko‘ngl"),
+    ("qorin", "qorn"),
+    ("bo‘yin", "bo‘yn"),
+    ("shahar", "shahr"),
+    ("bag‘ir", "bag‘r"),
+    ("ko‘kil", "ko‘kl"),
+    ("qayin", "qayn"),
+    ("egin", "egn"),
+];
+
+/// Words of several syllables keeping final "q" before a vowel, as in "axloqi".
+pub(super) const NO_VOICING: [&str; 6] = [
+    "axloq", "ittifoq", "ishtiyoq", "huquq", "mantiq", "iroq",
+];
@@ -0,0 +1,868 @@
+//! Morphological analysis of Uzbek words with a bundled lexicon.
+//!
+//! Implemented for both latin and cyrillic modes.
+mod constants;
+
+use crate::uzbek::{letter, script, Script};
+use crate::uzbek::letter::is_vowel;
+
+/// Part of speech from the Universal Dependencies tag set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartOfSpeech {
+    /// Adjective like "katta".
+    Adj,
+    /// Adposition like "uchun" or "bilan".
+    Adp,
+    /// Adverb like "tez".
+    Adv,
+    /// Auxiliary like "edi" or "emas".
+    Aux,
+    /// Coordinating conjunction like "va".
+    Cconj,
+    /// Determiner like "bu".
+    Det,
+    /// Interjection like "voy".
+    Intj,
+    /// Noun like "kitob".
+    Noun,
+    /// Numeral like "ikki".
+    Num,
+    /// Particle like "ham".
+    Part,
+    /// Pronoun like "men".
+    Pron,
+    /// Proper noun like "Toshkent".
+    Propn,
+    /// Punctuation mark.
+    Punct,
+    /// Subordinating conjunction like "chunki".
+    Sconj,
+    /// Symbol like "%".
+    Sym,
+    /// Verb like "bormoq".
+    Verb,
+    /// Any other word.
+    X,
+}
+
+impl PartOfSpeech {
+    /// Returns the Universal Dependencies tag like "NOUN".
+    ///
+    /// # Example
+    /// ```rust
+    /// use korrektor::uzbek::morphology::PartOfSpeech;
+    ///
+    /// assert_eq!(PartOfSpeech::Propn.tag(), "PROPN");
+    /// ```
+    pub fn tag(&self) -> &'static str {
+        match self {
+            PartOfSpeech::Adj => "ADJ",
+            PartOfSpeech::Adp => "ADP",
+            PartOfSpeech::Adv => "ADV",
+            PartOfSpeech::Aux => "AUX",
+            PartOfSpeech::Cconj => "CCONJ",
+            PartOfSpeech::Det => "DET",
+            PartOfSpeech::Intj => "INTJ",
+            PartOfSpeech::Noun => "NOUN",
+            PartOfSpeech::Num => "NUM",
+            PartOfSpeech::Part => "PART",
+            PartOfSpeech::Pron => "PRON",
+            PartOfSpeech::Propn => "PROPN",
+            PartOfSpeech::Punct => "PUNCT",
+            PartOfSpeech::Sconj => "SCONJ",
+            PartOfSpeech::Sym => "SYM",
+            PartOfSpeech::Verb => "VERB",
+            PartOfSpeech::X => "X",
+        }
+    }
+}
+
+/// Suffix of a word with its grammatical meaning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Morpheme {
+    /// Suffix as written in the word, like "im".
+    pub form: String,
+    /// Grammatical meaning of the suffix, like "POSS.1SG" or "LOC".
+    pub gloss: &'static str,
+}
+
+/// Possible parse of a word returned by [`analyze`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    /// Dictionary form of the word, like "qishloq".
+    pub lemma: String,
+    /// Part of speech of the lemma.
+    pub pos: PartOfSpeech,
+    /// Stem as written in the word, like "qishlog‘".
+    pub stem: String,
+    /// Suffixes in the order of their attachment.
+    pub suffixes: Vec<Morpheme>,
+}
+
+/// Person and number of possessive suffixes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Person {
+    /// "men", first person singular.
+    FirstSingular,
+    /// "sen", second person singular.
+    SecondSingular,
+    /// "u", third person singular.
+    ThirdSingular,
+    /// "biz", first person plural.
+    FirstPlural,
+    /// "siz", second person plural or polite form.
+    SecondPlural,
+    /// "ular", third person plural.
+    ThirdPlural,
+}
+
+impl Person {
+    /// All persons in the order of paradigms.
+    pub const ALL: [Person; 6] = [
+        Person::FirstSingular, Person::SecondSingular, Person::ThirdSingular,
+        Person::FirstPlural, Person::SecondPlural, Person::ThirdPlural,
+    ];
+}
+
+/// Grammatical case of nouns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// Bosh kelishik without a suffix, "kitob".
+    Nominative,
+    /// Qaratqich kelishigi "-ning", "kitobning".
+    Genitive,
+    /// Tushum kelishigi "-ni", "kitobni".
+    Accusative,
+    /// Jo‘nalish kelishigi "-ga", "-ka" or "-qa", "kitobga".
+    Dative,
+    /// O‘rin-payt kelishigi "-da", "kitobda".
+    Locative,
+    /// Chiqish kelishigi "-dan", "kitobdan".
+    Ablative,
+}
+
+impl Case {
+    /// All cases in the order of paradigms.
+    pub const ALL: [Case; 6] = [
+        Case::Nominative, Case::Genitive, Case::Accusative,
+        Case::Dative, Case::Locative, Case::Ablative,
+    ];
+}
+
+/// Tense and aspect of verbs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tense {
+    /// Hozirgi-kelasi zamon "-a" or "-y", "boraman".
+    Present,
+    /// O‘tgan zamon "-di", "bordim".
+    Past,
+    /// O‘tgan zamon sifatdoshi "-gan", "borganman".
+    Perfect,
+    /// Hozirgi davomli zamon "-yap", "boryapman".
+    Progressive,
+}
+
+impl Tense {
+    /// All tenses in the order of paradigms.
+    pub const ALL: [Tense; 4] = [Tense::Present, Tense::Past, Tense::Perfect, Tense::Progressive];
+}
+
+/// Grammatical form of a verb built by [`conjugate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Form {
+    /// Tense and aspect.
+    pub tense: Tense,
+    /// Person and number of the subject.
+    pub person: Person,
+    /// Negation with "-ma".
+    pub negative: bool,
+    /// Question particle "-mi".
+    pub question: bool,
+}
+
+/// Suffix with a condition on the preceding letter: "" for any letter,
+/// "V" for a vowel, "C" for a consonant, otherwise the letter itself.
+type Suffix = (&'static str, &'static str, &'static str);
+
+const QUESTION: [Suffix; 1] = [("mi", "", "Q")];
+
+const CASES: [Suffix; 9] = [
+    ("ning", "", "GEN"), ("ni", "", "ACC"), ("ga", "", "DAT"), ("ka", "k", "DAT"), ("qa", "q", "DAT"),
+    ("da", "", "LOC"), ("dan", "", "ABL"), ("gacha", "", "TERM"), ("dagi", "", "ATTR"),
+];
+
+const POSSESSIVES: [Suffix; 10] = [
+    ("im", "C", "POSS.1SG"), ("m", "V", "POSS.1SG"), ("ing", "C", "POSS.2SG"), ("ng", "V", "POSS.2SG"),
+    ("i", "C", "POSS.3"), ("si", "V", "POSS.3"), ("imiz", "C", "POSS.1PL"), ("miz", "V", "POSS.1PL"),
+    ("ingiz", "C", "POSS.2PL"), ("ngiz", "V", "POSS.2PL"),
+];
+
+const PLURAL: [Suffix; 1] = [("lar", "", "PL")];
+
+const PRESENT_PERSONS: [(&str, &str); 6] = [
+    ("man", "1SG"), ("san", "2SG"), ("di", "3"), ("miz", "1PL"), ("siz", "2PL"), ("dilar", "3PL"),
+];
+
+const PAST_PERSONS: [(&str, &str); 6] = [
+    ("m", "1SG"), ("ng", "2SG"), ("", "3"), ("k", "1PL"), ("ngiz", "2PL"), ("lar", "3PL"),
+];
+
+const COPULA_PERSONS: [(&str, &str); 6] = [
+    ("man", "1SG"), ("san", "2SG"), ("", "3"), ("miz", "1PL"), ("siz", "2PL"), ("lar", "3PL"),
+];
+
+const PROGRESSIVE_PERSONS: [(&str, &str); 6] = [
+    ("man", "1SG"), ("san", "2SG"), ("ti", "3"), ("miz", "1PL"), ("siz", "2PL"), ("tilar", "3PL"),
+];
+
+/// Tense suffixes with the person endings following them.
+const TENSES: [(Suffix, &[(&str, &str)]); 12] = [
+    (("a", "C", "PRS"), &PRESENT_PERSONS),
+    (("y", "V", "PRS"), &PRESENT_PERSONS),
+    (("di", "", "PST"), &PAST_PERSONS),
+    (("sa", "", "COND"), &PAST_PERSONS),
+    (("gan", "", "PRF"), &COPULA_PERSONS),
+    (("kan", "k", "PRF"), &COPULA_PERSONS),
+    (("qan", "q", "PRF"), &COPULA_PERSONS),
+    (("yap", "", "PROG"), &PROGRESSIVE_PERSONS),
+    (("moqda", "", "PROG"), &COPULA_PERSONS),
+    (("moq", "", "INF"), &[("", "")]),
+    (("ib", "C", "CVB"), &[("", "")]),
+    (("b", "V", "CVB"), &[("", "")]),
+];
+
+const NEGATION: Suffix = ("ma", "", "NEG");
+
+/// Pronouns and determiners taking "n" before dative, locative and ablative, as in "unga".
+const PRONOMINAL_N: [&str; 4] = ["u", "bu", "shu", "o‘sha"];
+
+/// Parts of speech which take plural, possessive and case suffixes.
+const NOMINALS: [PartOfSpeech; 6] = [
+    PartOfSpeech::Noun, PartOfSpeech::Propn, PartOfSpeech::Pron,
+    PartOfSpeech::Adj, PartOfSpeech::Num, PartOfSpeech::Det,
+];
+
+/// Returns all possible parses of a word by the bundled lexicon.
+///
+/// Nouns are parsed as a lemma with plural, possessive and case suffixes,
+/// verbs as a lemma with negation, tense and person suffixes,
+/// both followed by the question particle "-mi".
+/// Lemmas are restored from stems with final "k" and "q" voiced to "g" and "g‘"
+/// before a vowel, like in "yuragim", and from stems with a dropped vowel, like in "og‘zim".
+/// Words of cyrillic script are returned in cyrillic.
+/// Words missing in the lexicon have no parses.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::morphology::{self, PartOfSpeech};
+///
+/// let output = morphology::analyze("qishlog‘imda");
+/// assert_eq!(output[0].lemma, "qishloq");
+/// assert_eq!(output[0].pos, PartOfSpeech::Noun);
+/// let suffixes: Vec<(&str, &str)> = output[0].suffixes.iter().map(|m| (m.form.as_str(), m.gloss)).collect();
+/// assert_eq!(suffixes, vec![("im", "POSS.1SG"), ("da", "LOC")]);
+///
+/// let output = morphology::analyze("bordim");
+/// assert_eq!(output[0].lemma, "bor");
+/// assert_eq!(output[0].pos, PartOfSpeech::Verb);
+/// let suffixes: Vec<(&str, &str)> = output[0].suffixes.iter().map(|m| (m.form.as_str(), m.gloss)).collect();
+/// assert_eq!(suffixes, vec![("di", "PST"), ("m", "1SG")]);
+/// ```
+pub fn analyze(word: &str) -> Vec<Analysis> {
+    let cyrillic = is_cyrillic(word);
+    let word = letter::normalize(&script::to_latin(word.trim()));
+    let mut result: Vec<Analysis> = Vec::new();
+
+    if word.is_empty() {
+        return result;
+    }
+
+    for (stem, suffixes) in split_nominal(&word) {
+        lookup(&mut result, &stem, suffixes, true);
+    }
+
+    for (stem, suffixes) in split_verbal(&word) {
+        lookup(&mut result, &stem, suffixes, false);
+    }
+
+    result.sort_by_key(|analysis| analysis.suffixes.len());
+
+    if cyrillic {
+        for analysis in result.iter_mut() {
+            analysis.lemma = script::to_cyrillic(&analysis.lemma);
+            analysis.stem = script::to_cyrillic(&analysis.stem);
+            for suffix in analysis.suffixes.iter_mut() {
+                suffix.form = script::to_cyrillic(&suffix.form);
+            }
+        }
+    }
+
+    result
+}
+
+/// Checks whether a word is a nominal or verbal lemma of the bundled lexicon.
+pub(crate) fn is_lemma(word: &str, verbal: bool) -> bool {
+    let word = letter::normalize(&script::to_latin(word));
+
+    constants::LEXICON.iter().any(|&(entry, pos)| {
+        letter::normalize(entry) == word && if verbal { pos == PartOfSpeech::Verb } else { NOMINALS.contains(&pos) }
+    })
+}
+
+/// Returns stems with nominal suffixes in the reverse order.
+fn split_nominal(word: &str) -> Vec<(String, Vec<Morpheme>)> {
+    let mut options = vec![(word.to_string(), Vec::new())];
+
+    for slot in [&QUESTION[..], &CASES, &POSSESSIVES, &PLURAL] {
+        let mut next = options.clone();
+
+        for (stem, suffixes) in &options {
+            for &(form, after, gloss) in slot {
+                if let Some(rest) = strip(stem, form, after) {
+                    let mut suffixes = suffixes.clone();
+                    suffixes.push(Morpheme { form: form.to_string(), gloss });
+                    next.push((rest.to_string(), suffixes));
+                }
+            }
+        }
+
+        options = next;
+    }
+
+    options
+}
+
+/// Returns stems with verbal suffixes in the reverse order.
+fn split_verbal(word: &str) -> Vec<(String, Vec<Morpheme>)> {
+    let mut result = Vec::new();
+    let mut words = vec![(word.to_string(), Vec::new())];
+
+    if let Some(rest) = strip(word, QUESTION[0].0, QUESTION[0].1) {
+        words.push((rest.to_string(), vec![Morpheme { form: QUESTION[0].0.to_string(), gloss: QUESTION[0].2 }]));
+    }
+
+    for (word, outer) in words {
+        // negative imperative, as in "olma"
+        if let Some(rest) = strip(&word, NEGATION.0, NEGATION.1) {
+            let mut suffixes = outer.clone();
+            suffixes.push(Morpheme { form: NEGATION.0.to_string(), gloss: NEGATION.2 });
+            result.push((rest.to_string(), suffixes));
+        }
+
+        for ((tense, after, gloss), persons) in TENSES {
+            for &(person, person_gloss) in persons {
+                let Some(rest) = word.strip_suffix(person) else { continue };
+
+                let mut suffixes = outer.clone();
+                if !person.is_empty() {
+                    suffixes.push(Morpheme { form: person.to_string(), gloss: person_gloss });
+                }
+
+                if let Some(stem) = strip(rest, tense, after) {
+                    let mut suffixes = suffixes.clone();
+                    suffixes.push(Morpheme { form: tense.to_string(), gloss });
+                    result.push((stem.to_string(), suffixes));
+                }
+
+                // negation takes "-y" of present tense and "-gan" of perfect, converbs are negated by "-may"
+                let negated = match (gloss, after) {
+                    ("PRS", "C") | ("PRF", "k") | ("PRF", "q") | ("CVB", _) => continue,
+                    _ => rest.strip_suffix(tense).and_then(|stem| strip(stem, NEGATION.0, NEGATION.1)),
+                };
+
+                if let Some(stem) = negated {
+                    suffixes.push(Morpheme { form: tense.to_string(), gloss });
+                    suffixes.push(Morpheme { form: NEGATION.0.to_string(), gloss: NEGATION.2 });
+                    result.push((stem.to_string(), suffixes));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Adds analyses of a stem found in the lexicon.
+fn lookup(result: &mut Vec<Analysis>, stem: &str, mut suffixes: Vec<Morpheme>, nominal: bool) {
+    suffixes.reverse();
+
+    let bare = suffixes.iter().all(|suffix| suffix.gloss == "Q");
+    let vowel_follows = suffixes.first().is_some_and(|suffix| suffix.form.starts_with(|c: char| "aeiou".contains(c)));
+    let mut lemmas = vec![(stem.to_string(), suffixes.clone())];
+
+    if vowel_follows {
+        if let Some(base) = stem.strip_suffix("g‘") {
+            lemmas.push((format!("{}q", base), suffixes.clone()));
+        } else if let Some(base) = stem.strip_suffix('g') {
+            lemmas.push((format!("{}k", base), suffixes.clone()));
+        }
+
+        for (lemma, bound) in constants::ELISION {
+            if bound == stem {
+                lemmas.push((lemma.to_string(), suffixes.clone()));
+            }
+        }
+    }
+
+    if let (Some(base), Some(first)) = (stem.strip_suffix('n'), suffixes.first()) {
+        if PRONOMINAL_N.contains(&base) && ["DAT", "LOC", "ABL"].contains(&first.gloss) {
+            let mut suffixes = suffixes.clone();
+            suffixes[0].form.insert(0, 'n');
+            lemmas.push((base.to_string(), suffixes));
+        }
+    }
+
+    for (lemma, suffixes) in lemmas {
+        let voiced = lemma != stem && !constants::ELISION.iter().any(|(elided, _)| *elided == lemma);
+
+        for &(entry, pos) in constants::LEXICON.iter() {
+            if letter::normalize(entry) != lemma {
+                continue;
+            }
+
+            let allowed = bare || if nominal { NOMINALS.contains(&pos) } else { pos == PartOfSpeech::Verb };
+            // only words of several syllables voice the final consonant, as in "yurak" and "yuragim"
+            let polysyllabic = lemma.chars().filter(|c| "aeiou".contains(*c)).count() > 1;
+
+            if !allowed || (voiced && !polysyllabic && lemma.ends_with(['k', 'q'])) {
+                continue;
+            }
+
+            let analysis = Analysis { lemma: entry.to_string(), pos, stem: stem.to_string(), suffixes: suffixes.clone() };
+            if !result.contains(&analysis) {
+                result.push(analysis);
+            }
+        }
+    }
+}
+
+/// Returns a form of a noun with plural, possessive and case suffixes.
+///
+/// Suffix variants are chosen by the last letter: "-m" or "-im", "-si" or "-i" and
+/// "-ga", "-ka" after "k" or "-qa" after "q". Final "k" and "q" of words with several
+/// syllables are voiced to "g" and "g‘" before a possessive vowel and some nouns
+/// drop the vowel of their last syllable, like "og‘iz" and "og‘zim".
+/// Words of cyrillic script get cyrillic suffixes, the case of the noun is kept.
+/// An empty noun gives an empty string.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::morphology::{self, Case, Person};
+///
+/// assert_eq!(morphology::inflect("Toshkent", false, None, Case::Dative), "Toshkentga");
+/// assert_eq!(morphology::inflect("qishloq", false, None, Case::Dative), "qishloqqa");
+/// assert_eq!(morphology::inflect("yurak", false, Some(Person::FirstSingular), Case::Nominative), "yuragim");
+/// assert_eq!(morphology::inflect("kitob", true, Some(Person::FirstPlural), Case::Ablative), "kitoblarimizdan");
+/// assert_eq!(morphology::inflect("бола", false, Some(Person::ThirdSingular), Case::Genitive), "боласининг");
+/// ```
+pub fn inflect(noun: &str, plural: bool, possessive: Option<Person>, case: Case) -> String {
+    let noun = noun.trim();
+    if noun.is_empty() {
+        return String::new();
+    }
+
+    let latin = letter::normalize(&script::to_latin(noun));
+    let mut stem = noun.to_string();
+    let mut suffix = String::new();
+
+    if plural {
+        suffix.push_str(PLURAL[0].0);
+    }
+
+    if let Some(person) = possessive {
+        let after_vowel = letter::letters(&latin).last().is_some_and(is_vowel) && !plural;
+        let ending = match person {
+            Person::FirstSingular if after_vowel => "m",
+            Person::FirstSingular => "im",
+            Person::SecondSingular if after_vowel => "ng",
+            Person::SecondSingular => "ing",
+            Person::ThirdSingular if after_vowel => "si",
+            Person::ThirdSingular => "i",
+            Person::FirstPlural if after_vowel => "miz",
+            Person::FirstPlural => "imiz",
+            Person::SecondPlural if after_vowel => "ngiz",
+            Person::SecondPlural => "ingiz",
+            Person::ThirdPlural if plural => "i",
+            Person::ThirdPlural => "lari",
+        };
+
+        if !plural && ending.starts_with('i') {
+            stem = alternate(noun, &latin);
+        }
+
+        suffix.push_str(ending);
+    }
+
+    let last = letter::letters(&suffix).last().or(letter::letters(&latin).last()).unwrap_or_default().to_string();
+    suffix.push_str(match case {
+        Case::Nominative => "",
+        Case::Genitive => "ning",
+        Case::Accusative => "ni",
+        Case::Dative if last == "k" => "ka",
+        Case::Dative if last == "q" => "qa",
+        Case::Dative => "ga",
+        Case::Locative => "da",
+        Case::Ablative => "dan",
+    });
+
+    join(noun, stem, &suffix)
+}
+
+/// Returns an attributive form of a noun of time or place with "-gi", "-ki" or "-qi".
+///
+/// "-qi" follows "q", "-ki" follows "k" and other voiceless consonants,
+/// otherwise "-gi" is used. An empty noun gives an empty string.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::morphology;
+///
+/// assert_eq!(morphology::attributive("kecha"), "kechagi");
+/// assert_eq!(morphology::attributive("qish"), "qishki");
+/// assert_eq!(morphology::attributive("ич"), "ички");
+/// ```
+pub fn attributive(noun: &str) -> String {
+    let noun = noun.trim();
+    if noun.is_empty() {
+        return String::new();
+    }
+
+    let latin = letter::normalize(&script::to_latin(noun));
+    let suffix = match letter::letters(&latin).last() {
+        Some("q") => "qi",
+        Some("k" | "p" | "t" | "s" | "sh" | "ch" | "f" | "x" | "h") => "ki",
+        _ => "gi",
+    };
+
+    join(noun, noun.to_string(), suffix)
+}
+
+/// Returns a form of a verb in the given script.
+///
+/// The verb is given as a stem like "bor" or as an infinitive like "bormoq".
+/// Present tense takes "-a" after consonants and "-y" after vowels and negation,
+/// perfect "-gan" becomes "-kan" after "k" and "-qan" after "q".
+/// A verb without a stem, like "" or "moq", gives an empty string.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::Script;
+/// use korrektor::uzbek::morphology::{self, Form, Person, Tense};
+///
+/// let form = Form { tense: Tense::Present, person: Person::FirstSingular, negative: false, question: false };
+/// assert_eq!(morphology::conjugate("bormoq", form, Script::Latin), "boraman");
+/// assert_eq!(morphology::conjugate("o‘qi", form, Script::Cyrillic), "ўқийман");
+///
+/// let form = Form { tense: Tense::Past, person: Person::SecondPlural, negative: true, question: true };
+/// assert_eq!(morphology::conjugate("kel", form, Script::Latin), "kelmadingizmi");
+/// ```
+pub fn conjugate(verb: &str, form: Form, script: Script) -> String {
+    let mut verb = verb.trim();
+    let lower = letter::normalize(verb);
+
+    for infinitive in ["moq", "моқ"] {
+        let stem = lower.strip_suffix(infinitive).map(script::to_latin);
+
+        if stem.is_some_and(|stem| letter::letters(&stem).any(is_vowel)) {
+            verb = &verb[..verb.len() - infinitive.len()];
+        }
+    }
+
+    // a bare infinitive suffix has no stem to conjugate
+    if verb.is_empty() || ["moq", "моқ"].contains(&letter::normalize(verb).as_str()) {
+        return String::new();
+    }
+
+    let latin = letter::normalize(&script::to_latin(verb));
+    let last = letter::letters(&latin).last().unwrap_or_default();
+    let mut suffix = String::new();
+
+    if form.negative {
+        suffix.push_str(NEGATION.0);
+    }
+
+    let after_vowel = form.negative || is_vowel(last);
+    let (tense, persons) = match form.tense {
+        Tense::Present if after_vowel => ("y", PRESENT_PERSONS),
+        Tense::Present => ("a", PRESENT_PERSONS),
+        Tense::Past => ("di", PAST_PERSONS),
+        Tense::Perfect if !form.negative && last == "k" => ("kan", COPULA_PERSONS),
+        Tense::Perfect if !form.negative && last == "q" => ("qan", COPULA_PERSONS),
+        Tense::Perfect => ("gan", COPULA_PERSONS),
+        Tense::Progressive => ("yap", PROGRESSIVE_PERSONS),
+    };
+
+    let index = Person::ALL.iter().position(|person| *person == form.person).unwrap_or(0);
+    suffix.push_str(tense);
+    suffix.push_str(persons[index].0);
+
+    if form.question {
+        suffix.push_str(QUESTION[0].0);
+    }
+
+    match (script, is_cyrillic(verb)) {
+        (Script::Latin, false) => format!("{}{}", verb, suffix),
+        (Script::Latin, true) => script::to_latin(&join(verb, verb.to_string(), &suffix)),
+        (Script::Cyrillic, true) => join(verb, verb.to_string(), &suffix),
+        (Script::Cyrillic, false) => script::to_cyrillic(&format!("{}{}", verb, suffix)),
+    }
+}
+
+/// Returns all forms of a verb built by [`conjugate`].
+///
+/// Forms are ordered by tense, negation, question and person.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::Script;
+/// use korrektor::uzbek::morphology;
+///
+/// let output = morphology::paradigm("yoz", Script::Latin);
+/// assert_eq!(output.len(), 96);
+/// let forms: Vec<&str> = output[..6].iter().map(|(_, form)| form.as_str()).collect();
+/// assert_eq!(forms, vec!["yozaman", "yozasan", "yozadi", "yozamiz", "yozasiz", "yozadilar"]);
+/// ```
+pub fn paradigm(verb: &str, script: Script) -> Vec<(Form, String)> {
+    let mut result = Vec::new();
+
+    for tense in Tense::ALL {
+        for negative in [false, true] {
+            for question in [false, true] {
+                for person in Person::ALL {
+                    let form = Form { tense, person, negative, question };
+                    result.push((form, conjugate(verb, form, script)));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns the stem of a noun before a possessive vowel.
+fn alternate(noun: &str, latin: &str) -> String {
+    let cyrillic = is_cyrillic(noun);
+
+    if let Some((_, bound)) = constants::ELISION.iter().find(|(lemma, _)| *lemma == latin) {
+        let bound = if cyrillic { script::to_cyrillic(bound) } else { bound.to_string() };
+        let mut chars = bound.chars();
+
+        return match (noun.chars().next(), chars.next()) {
+            (Some(first), Some(bound_first)) if first.is_uppercase() => bound_first.to_uppercase().chain(chars).collect(),
+            _ => bound,
+        };
+    }
+
+    let syllables = letter::letters(latin).filter(|value| is_vowel(value)).count();
+    let Some((start, last)) = letter::letter_indices(noun).last() else { return noun.to_string() };
+
+    if syllables < 2 || constants::NO_VOICING.contains(&latin) {
+        return noun.to_string();
+    }
+
+    let voiced = match letter::normalize(last).as_str() {
+        "k" => "g",
+        "q" => "g‘",
+        "к" => "г",
+        "қ" => "ғ",
+        _ => return noun.to_string(),
+    };
+
+    let voiced = if last.chars().all(char::is_uppercase) { voiced.to_uppercase() } else { voiced.to_string() };
+    format!("{}{}", &noun[..start], voiced)
+}
+
+/// Joins a stem with latin suffixes written in the script of the noun.
+fn join(noun: &str, stem: String, suffix: &str) -> String {
+    if is_cyrillic(noun) {
+        stem + &script::to_cyrillic(suffix)
+    } else {
+        stem + suffix
+    }
+}
+
+fn is_cyrillic(text: &str) -> bool {
+    text.chars().any(letter::is_cyrillic)
+}
+
+/// Strips a suffix, if the rest is a possible stem ending with the required letter.
+fn strip<'a>(word: &'a str, suffix: &str, after: &str) -> Option<&'a str> {
+    let rest = word.strip_suffix(suffix)?;
+    let last = letter::letters(rest).last()?;
+    let vowel = is_vowel(last);
+
+    let allowed = match after {
+        "" => true,
+        "V" => vowel,
+        "C" => !vowel,
+        _ => last == after,
+    };
+
+    allowed.then_some(rest)
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    fn parses(word: &str) -> Vec<String> {
+        analyze(word)
+            .iter()
+            .map(|analysis| {
+                let mut parts = vec![format!("{}/{}", analysis.lemma, analysis.pos.tag())];
+                parts.extend(analysis.suffixes.iter().map(|suffix| format!("{}/{}", suffix.form, suffix.gloss)));
+                parts.join("+")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn noun_test() {
+        assert_eq!(parses("kitoblarimizdan"), vec!["kitob/NOUN+lar/PL+imiz/POSS.1PL+dan/ABL"]);
+        assert_eq!(parses("qishlog‘imda"), vec!["qishloq/NOUN+im/POSS.1SG+da/LOC"]);
+        assert_eq!(parses("yuragim"), vec!["yurak/NOUN+im/POSS.1SG"]);
+        assert_eq!(parses("og‘zimni"), vec!["og‘iz/NOUN+im/POSS.1SG+ni/ACC"]);
+        assert_eq!(parses("ko‘kka"), vec!["ko‘k/ADJ+ka/DAT"]);
+        assert_eq!(parses("Toshkentgacha"), vec!["Toshkent/PROPN+gacha/TERM"]);
+        assert_eq!(parses("unga"), vec!["u/PRON+nga/DAT", "u/DET+nga/DAT"]);
+    }
+
+    #[test]
+    fn verb_test() {
+        assert_eq!(parses("bordim"), vec!["bor/VERB+di/PST+m/1SG"]);
+        assert_eq!(parses("bormadingizmi"), vec!["bor/VERB+ma/NEG+di/PST+ngiz/2PL+mi/Q"]);
+        assert_eq!(parses("o‘qiyapman"), vec!["o‘qi/VERB+yap/PROG+man/1SG"]);
+        assert_eq!(parses("kelmaydi"), vec!["kel/VERB+ma/NEG+y/PRS+di/3"]);
+        assert_eq!(parses("ko‘ramiz"), vec!["ko‘r/VERB+a/PRS+miz/1PL"]);
+        assert_eq!(parses("chiqqan"), vec!["chiq/VERB+qan/PRF"]);
+        assert_eq!(parses("sotib"), vec!["sot/VERB+ib/CVB"]);
+        assert_eq!(parses("o‘qib"), vec!["o‘qi/VERB+b/CVB"]);
+    }
+
+    #[test]
+    fn ambiguous_test() {
+        assert_eq!(parses("olma"), vec!["olma/NOUN", "ol/VERB+ma/NEG"]);
+        assert_eq!(parses("bor"), vec!["bor/VERB", "bor/ADJ"]);
+        assert_eq!(parses("yozda"), vec!["yoz/NOUN+da/LOC"]);
+        assert_eq!(parses("kitoblari"), vec!["kitob/NOUN+lar/PL+i/POSS.3"]);
+        assert_eq!(parses("uydagi"), vec!["uy/NOUN+dagi/ATTR"]);
+    }
+
+    #[test]
+    fn cyrillic_test() {
+        let output = analyze("қишлоғимда");
+
+        assert_eq!(output.len(), 1);
+        assert_eq!(output[0].lemma, "қишлоқ");
+        assert_eq!(output[0].stem, "қишлоғ");
+        assert_eq!(output[0].suffixes[0].form, "им");
+        assert_eq!(parses("бормайман"), vec!["бор/VERB+ма/NEG+й/PRS+ман/1SG"]);
+    }
+
+    #[test]
+    fn inflect_test() {
+        let forms: Vec<String> = Case::ALL.iter().map(|&case| inflect("ko‘k", false, None, case)).collect();
+        assert_eq!(forms, vec!["ko‘k", "ko‘kning", "ko‘kni", "ko‘kka", "ko‘kda", "ko‘kdan"]);
+
+        let forms: Vec<String> = Person::ALL.iter().map(|&person| inflect("bola", false, Some(person), Case::Nominative)).collect();
+        assert_eq!(forms, vec!["bolam", "bolang", "bolasi", "bolamiz", "bolangiz", "bolalari"]);
+
+        let forms: Vec<String> = Person::ALL.iter().map(|&person| inflect("qishloq", false, Some(person), Case::Nominative)).collect();
+        assert_eq!(forms, vec!["qishlog‘im", "qishlog‘ing", "qishlog‘i", "qishlog‘imiz", "qishlog‘ingiz", "qishloqlari"]);
+
+        assert_eq!(inflect("Buxoro", false, None, Case::Dative), "Buxoroga");
+        assert_eq!(inflect("yurak", false, Some(Person::ThirdSingular), Case::Dative), "yuragiga");
+        assert_eq!(inflect("kitob", true, Some(Person::ThirdPlural), Case::Accusative), "kitoblarini");
+        assert_eq!(inflect("yurak", true, Some(Person::FirstSingular), Case::Nominative), "yuraklarim");
+        assert_eq!(inflect("tok", false, Some(Person::FirstSingular), Case::Nominative), "tokim");
+        assert_eq!(inflect("axloq", false, Some(Person::ThirdSingular), Case::Nominative), "axloqi");
+    }
+
+    #[test]
+    fn elision_test() {
+        assert_eq!(inflect("og‘iz", false, Some(Person::FirstSingular), Case::Nominative), "og‘zim");
+        assert_eq!(inflect("O‘g‘il", false, Some(Person::ThirdSingular), Case::Nominative), "O‘g‘li");
+        assert_eq!(inflect("shahar", false, Some(Person::FirstPlural), Case::Locative), "shahrimizda");
+        assert_eq!(inflect("shahar", false, None, Case::Locative), "shaharda");
+        assert_eq!(inflect("singil", false, Some(Person::SecondSingular), Case::Nominative), "singling");
+    }
+
+    #[test]
+    fn inflect_cyrillic_test() {
+        assert_eq!(inflect("Тошкент", false, None, Case::Dative), "Тошкентга");
+        assert_eq!(inflect("қишлоқ", false, None, Case::Dative), "қишлоққа");
+        assert_eq!(inflect("қишлоқ", false, Some(Person::FirstSingular), Case::Locative), "қишлоғимда");
+        assert_eq!(inflect("юрак", false, Some(Person::SecondPlural), Case::Nominative), "юрагингиз");
+        assert_eq!(inflect("оғиз", false, Some(Person::ThirdSingular), Case::Nominative), "оғзи");
+        assert_eq!(inflect("китоб", true, None, Case::Genitive), "китобларнинг");
+    }
+
+    #[test]
+    fn attributive_test() {
+        assert_eq!(attributive("kecha"), "kechagi");
+        assert_eq!(attributive("bugun"), "bugungi");
+        assert_eq!(attributive("yoz"), "yozgi");
+        assert_eq!(attributive("ich"), "ichki");
+        assert_eq!(attributive("kech"), "kechki");
+        assert_eq!(attributive("Қиш"), "Қишки");
+    }
+
+    fn forms(verb: &str, tense: Tense, negative: bool, question: bool, script: Script) -> Vec<String> {
+        Person::ALL
+            .iter()
+            .map(|&person| conjugate(verb, Form { tense, person, negative, question }, script))
+            .collect()
+    }
+
+    #[test]
+    fn conjugate_test() {
+        assert_eq!(forms("bor", Tense::Present, false, false, Script::Latin), vec!["boraman", "borasan", "boradi", "boramiz", "borasiz", "boradilar"]);
+        assert_eq!(forms("bor", Tense::Present, true, false, Script::Latin), vec!["bormayman", "bormaysan", "bormaydi", "bormaymiz", "bormaysiz", "bormaydilar"]);
+        assert_eq!(forms("bor", Tense::Past, false, true, Script::Latin), vec!["bordimmi", "bordingmi", "bordimi", "bordikmi", "bordingizmi", "bordilarmi"]);
+        assert_eq!(forms("o‘qi", Tense::Present, false, false, Script::Latin), vec!["o‘qiyman", "o‘qiysan", "o‘qiydi", "o‘qiymiz", "o‘qiysiz", "o‘qiydilar"]);
+        assert_eq!(forms("o‘qimoq", Tense::Progressive, false, false, Script::Latin), vec!["o‘qiyapman", "o‘qiyapsan", "o‘qiyapti", "o‘qiyapmiz", "o‘qiyapsiz", "o‘qiyaptilar"]);
+        assert_eq!(forms("kel", Tense::Progressive, true, true, Script::Latin)[2], "kelmayaptimi");
+        assert_eq!(forms("chiq", Tense::Perfect, false, false, Script::Latin), vec!["chiqqanman", "chiqqansan", "chiqqan", "chiqqanmiz", "chiqqansiz", "chiqqanlar"]);
+        assert_eq!(forms("ek", Tense::Perfect, false, false, Script::Latin)[2], "ekkan");
+        assert_eq!(forms("chiq", Tense::Perfect, true, false, Script::Latin)[2], "chiqmagan");
+    }
+
+    #[test]
+    fn conjugate_script_test() {
+        assert_eq!(forms("ўқи", Tense::Present, true, false, Script::Cyrillic), vec!["ўқимайман", "ўқимайсан", "ўқимайди", "ўқимаймиз", "ўқимайсиз", "ўқимайдилар"]);
+        assert_eq!(forms("ёзмоқ", Tense::Past, false, false, Script::Cyrillic), vec!["ёздим", "ёздинг", "ёзди", "ёздик", "ёздингиз", "ёздилар"]);
+        assert_eq!(forms("чиқ", Tense::Perfect, false, false, Script::Latin)[0], "chiqqanman");
+        assert_eq!(forms("yoz", Tense::Progressive, false, true, Script::Cyrillic)[1], "ёзяпсанми");
+        assert_eq!(forms("Ye", Tense::Present, false, false, Script::Cyrillic)[0], "Ейман");
+    }
+
+    #[test]
+    fn empty_test() {
+        let form = Form { tense: Tense::Present, person: Person::FirstSingular, negative: false, question: false };
+
+        assert_eq!(inflect("", false, None, Case::Dative), "");
+        assert_eq!(inflect("  ", false, Some(Person::FirstSingular), Case::Dative), "");
+        assert_eq!(attributive(""), "");
+        assert_eq!(conjugate("", form, Script::Latin), "");
+        assert_eq!(conjugate("moq", form, Script::Latin), "");
+        assert_eq!(conjugate(" МОҚ ", form, Script::Cyrillic), "");
+        assert!(analyze("").is_empty());
+    }
+
+    #[test]
+    fn paradigm_test() {
+        let output = paradigm("kel", Script::Latin);
+
+        assert_eq!(output.len(), 96);
+        assert_eq!(output[0].1, "kelaman");
+        assert_eq!(output[95].0, Form { tense: Tense::Progressive, person: Person::ThirdPlural, negative: true, question: true });
+        assert_eq!(output[95].1, "kelmayaptilarmi");
+    }
+
+    #[test]
+    fn unknown_test() {
+        assert!(analyze("abrakadabra").is_empty());
+        assert!(analyze("").is_empty());
+        assert!(analyze("kitobg").is_empty());
+    }
+}
@@ -0,0 +1,437 @@
+//! Functions to analyze meter and rhyme of Uzbek poetry.
+//!
+//! Implemented for both latin and cyrillic modes.
+use std::ops::Range;
+use crate::uzbek::{letter, script};
+use crate::uzbek::tokenize::{self, SyllableKind, TokenKind};
+
+/// Common caesura patterns of barmoq meter by number of syllables in a line.
+const BARMOQ: [(usize, &[&str]); 10] = [
+    (7, &["4+3", "3+4"]),
+    (8, &["4+4", "5+3", "3+5"]),
+    (9, &["4+5", "5+4", "3+3+3"]),
+    (10, &["5+5", "4+3+3", "6+4"]),
+    (11, &["4+4+3", "4+3+4", "3+4+4", "6+5"]),
+    (12, &["4+4+4", "6+6", "4+3+5"]),
+    (13, &["4+4+5", "4+5+4"]),
+    (14, &["7+7", "4+3+4+3"]),
+    (15, &["4+4+4+3", "5+5+5"]),
+    (16, &["4+4+4+4", "8+8"]),
+];
+
+/// Common grammatical suffixes making a syllable, which alone do not make a rhyme, as in "keldi" and "bordi".
+const SUFFIXES: [&str; 15] = [
+    "di", "da", "ga", "ka", "qa", "ni", "dan", "ning", "lar", "gan", "dir", "man", "san", "miz", "siz",
+];
+
+/// Common meters of aruz with their feet and patterns of heavy "-" and light "U" syllables.
+const ARUZ: [(Bahr, &str, &str, &str); 8] = [
+    (Bahr::Ramal, "ramali musammani mahzuf", "foilotun foilotun foilotun foilun", "-U---U---U---U-"),
+    (Bahr::Ramal, "ramali musaddasi mahzuf", "foilotun foilotun foilun", "-U---U---U-"),
+    (Bahr::Ramal, "ramali musammani maxbuni mahzuf", "foilotun failotun failotun failun", "-U--UU--UU--UU-"),
+    (Bahr::Hazaj, "hazaji musammani solim", "mafoiylun mafoiylun mafoiylun mafoiylun", "U---U---U---U---"),
+    (Bahr::Hazaj, "hazaji musaddasi mahzuf", "mafoiylun mafoiylun faulun", "U---U---U--"),
+    (Bahr::Hazaj, "hazaji musammani axrabi makfufi mahzuf", "maf’ulu mafoiylu mafoiylu faulun", "--UU--UU--UU--"),
+    (Bahr::Mutaqorib, "mutaqoribi musammani solim", "faulun faulun faulun faulun", "U--U--U--U--"),
+    (Bahr::Mutaqorib, "mutaqoribi musammani mahzuf", "faulun faulun faulun faal", "U--U--U--U-"),
+];
+
+/// Bahr of aruz meter found by [`scan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bahr {
+    /// Ramal built on the foot "foilotun".
+    Ramal,
+    /// Hazaj built on the foot "mafoiylun".
+    Hazaj,
+    /// Mutaqorib built on the foot "faulun".
+    Mutaqorib,
+}
+
+/// Aruz scansion of a line returned by [`scan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scansion<'a> {
+    /// Text of the line without surrounding whitespace.
+    pub text: &'a str,
+    /// Byte range of the line in the input.
+    pub span: Range<usize>,
+    /// Heavy "-" and light "U" syllables of the line.
+    pub pattern: String,
+    /// Bahr of the closest meter.
+    pub bahr: Bahr,
+    /// Name of the closest meter like "ramali musammani mahzuf".
+    pub meter: &'static str,
+    /// Feet of the closest meter like "foilotun foilotun foilotun foilun".
+    pub feet: &'static str,
+    /// Number of syllables to change, add or remove to get the meter pattern.
+    pub mismatches: usize,
+}
+
+/// Line of a poem found by [`analyze`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// Text of the line without surrounding whitespace.
+    pub text: &'a str,
+    /// Byte range of the line in the input.
+    pub span: Range<usize>,
+    /// Number of syllables in the line.
+    pub syllables: usize,
+    /// Caesura pattern of barmoq meter like "4+4+3", if the line fits one.
+    pub caesura: Option<String>,
+    /// Rhyme letter of the line, lines with the same letter rhyme.
+    pub rhyme: char,
+    /// Whether the line ends with the radif of the poem.
+    pub radif: bool,
+}
+
+/// Meter and rhyme of a poem returned by [`analyze`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poem<'a> {
+    /// Lines with words, empty lines between stanzas are skipped.
+    pub lines: Vec<Line<'a>>,
+    /// Rhyme scheme like "AABA".
+    pub scheme: String,
+    /// Words repeated at the end of rhyming lines, as written in the first line.
+    pub radif: Option<&'a str>,
+}
+
+/// Returns barmoq meter, rhyme scheme and radif of a poem.
+///
+/// Syllables of each line are counted by [`tokenize::syllables`] and the caesura
+/// pattern is the first common pattern for that number of syllables,
+/// whose breaks fall between words.
+/// Radif is the longest ending of the first line repeated in at least half of the other lines.
+/// Lines rhyme, when the last syllables before the radif have the same vowel and ending consonants.
+/// A last syllable being a common suffix like "-di" or "-ga" rhymes only with the syllable before it.
+/// Lines of both scripts are compared through the latin alphabet.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::poetry;
+///
+/// let poem = poetry::analyze("Yurtim obod bo‘lsin doim,\nElim shod bo‘lsin doim,\nBog‘da gullar ochilsin,\nXalqim ozod bo‘lsin doim.");
+/// assert_eq!(poem.scheme, "AABA");
+/// assert_eq!(poem.radif, Some("bo‘lsin doim"));
+/// assert_eq!(poem.lines[0].syllables, 8);
+/// assert_eq!(poem.lines[0].caesura, Some("4+4".to_string()));
+/// ```
+pub fn analyze(text: &str) -> Poem<'_> {
+    let entries = get_lines(text);
+    let keys: Vec<Vec<String>> = entries
+        .iter()
+        .map(|(_, tokens)| tokens.iter().map(|token| letter::normalize(&script::to_latin(token.text))).collect())
+        .collect();
+    let length = find_radif(&keys);
+
+    let mut rhymes: Vec<String> = Vec::new();
+    let mut scheme = String::new();
+    let mut lines = Vec::new();
+
+    for ((span, tokens), words) in entries.iter().zip(&keys) {
+        let radif = length > 0 && words.ends_with(&keys[0][keys[0].len() - length..]);
+        let last = if radif { words.len() - length } else { words.len() };
+        let rhyme = last.checked_sub(1).map(|index| get_rime(&words[index])).unwrap_or_default();
+
+        let index = rhymes.iter().position(|known| *known == rhyme).unwrap_or_else(|| {
+            rhymes.push(rhyme);
+            rhymes.len() - 1
+        });
+        let rhyme = char::from_u32('A' as u32 + index as u32).unwrap_or('?');
+        scheme.push(rhyme);
+
+        let counts: Vec<usize> = tokens.iter().map(|token| tokenize::syllables(token.text).len()).collect();
+
+        lines.push(Line {
+            text: &text[span.clone()],
+            span: span.clone(),
+            syllables: counts.iter().sum(),
+            caesura: get_caesura(&counts),
+            rhyme,
+            radif,
+        });
+    }
+
+    let radif = (length > 0).then(|| {
+        let (span, tokens) = &entries[0];
+        let first = &tokens[tokens.len() - length];
+        let last = &tokens[tokens.len() - 1];
+        &text[span.start + first.span.start..span.start + last.span.end]
+    });
+
+    Poem { lines, scheme, radif }
+}
+
+/// Returns aruz scansion of each line of a poem.
+///
+/// Syllables are found by [`tokenize::syllables`]. Open syllables are light,
+/// closed syllables are heavy and syllables ending with two consonants, like "daraxt",
+/// are heavy with an additional light one. The last syllable of a line is always heavy.
+/// Vowel length is not written in modern spelling, so the line is matched
+/// with the closest common meter of ramal, hazaj and mutaqorib bahrs
+/// and the number of mismatched syllables is reported.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::poetry::{self, Bahr};
+///
+/// let output = poetry::scan("Kecha kelgumdur debon ul sarvi gulro‘ kelmadi");
+/// assert_eq!(output[0].pattern, "UU---U---U-U-U-");
+/// assert_eq!(output[0].bahr, Bahr::Ramal);
+/// assert_eq!(output[0].meter, "ramali musammani mahzuf");
+/// assert_eq!(output[0].mismatches, 2);
+/// ```
+pub fn scan(text: &str) -> Vec<Scansion<'_>> {
+    let mut result = Vec::new();
+
+    for (span, tokens) in get_lines(text) {
+        let pattern = get_weights(&tokens);
+        let (bahr, meter, feet, mismatches) = ARUZ
+            .iter()
+            .map(|(bahr, meter, feet, target)| (*bahr, *meter, *feet, distance(&pattern, target)))
+            .min_by_key(|(_, _, _, mismatches)| *mismatches)
+            .unwrap_or((Bahr::Ramal, ARUZ[0].1, ARUZ[0].2, pattern.len()));
+
+        result.push(Scansion { text: &text[span.clone()], span, pattern, bahr, meter, feet, mismatches });
+    }
+
+    result
+}
+
+/// Returns non-empty lines of a text with their word tokens.
+fn get_lines(text: &str) -> Vec<(Range<usize>, Vec<tokenize::Token<'_>>)> {
+    let mut result = Vec::new();
+    let mut start = 0;
+
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim();
+        let offset = start + (line.len() - line.trim_start().len());
+        let tokens: Vec<tokenize::Token> = tokenize::words(trimmed)
+            .into_iter()
+            .filter(|token| token.kind == TokenKind::Word)
+            .collect();
+
+        if !tokens.is_empty() {
+            result.push((offset..offset + trimmed.len(), tokens));
+        }
+
+        start += line.len();
+    }
+
+    result
+}
+
+/// Returns heavy "-" and light "U" syllables of words.
+fn get_weights(tokens: &[tokenize::Token]) -> String {
+    let mut weights: Vec<&str> = tokens
+        .iter()
+        .flat_map(|token| tokenize::syllables(token.text))
+        .map(|syllable| match syllable.kind {
+            SyllableKind::Open => "U",
+            SyllableKind::Closed if syllable.pattern.ends_with("VCC") => "-U",
+            SyllableKind::Closed => "-",
+        })
+        .collect();
+
+    if let Some(last) = weights.last_mut() {
+        *last = "-";
+    }
+
+    weights.concat()
+}
+
+/// Returns edit distance between two patterns.
+fn distance(left: &str, right: &str) -> usize {
+    let right: Vec<char> = right.chars().collect();
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+
+    for (i, a) in left.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, b) in right.iter().enumerate() {
+            let cost = if a == *b { previous[j] } else { previous[j] + 1 };
+            current.push(cost.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[right.len()]
+}
+
+/// Returns number of words in the radif, or 0 if the poem has none.
+fn find_radif(lines: &[Vec<String>]) -> usize {
+    let Some((first, others)) = lines.split_first() else { return 0 };
+
+    if others.is_empty() {
+        return 0;
+    }
+
+    for length in (1..first.len()).rev() {
+        let ending = &first[first.len() - length..];
+        let count = others
+            .iter()
+            .filter(|words| words.len() > length && words.ends_with(ending))
+            .count();
+
+        if count > 0 && count * 2 >= others.len() {
+            return length;
+        }
+    }
+
+    0
+}
+
+/// Returns vowel and ending consonants of the last syllable.
+///
+/// When the last syllable is a common suffix, it is taken whole after the rime
+/// of the syllable before it, so "ochildi" gives "ildi".
+fn get_rime(word: &str) -> String {
+    let syllables = tokenize::syllables(word);
+    let Some(last) = syllables.last() else { return word.to_string() };
+
+    let rime = |syllable: &tokenize::Syllable| match syllable.pattern.find('V') {
+        Some(index) => letter::letters(syllable.text).skip(index).collect(),
+        None => syllable.text.to_string(),
+    };
+
+    match syllables.len().checked_sub(2).map(|index| &syllables[index]) {
+        Some(previous) if SUFFIXES.contains(&letter::normalize(last.text).as_str()) => rime(previous) + last.text,
+        _ => rime(last),
+    }
+}
+
+/// Returns the first caesura pattern, whose breaks fall between words with given numbers of syllables.
+fn get_caesura(counts: &[usize]) -> Option<String> {
+    let total: usize = counts.iter().sum();
+    let (_, patterns) = BARMOQ.iter().find(|(syllables, _)| *syllables == total)?;
+
+    let bounds: Vec<usize> = counts
+        .iter()
+        .scan(0, |sum, count| {
+            *sum += count;
+            Some(*sum)
+        })
+        .collect();
+
+    patterns
+        .iter()
+        .find(|pattern| {
+            pattern
+                .split('+')
+                .filter_map(|part| part.parse::<usize>().ok())
+                .scan(0, |sum, part| {
+                    *sum += part;
+                    Some(*sum)
+                })
+                .all(|bound| bounds.contains(&bound))
+        })
+        .map(|pattern| pattern.to_string())
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    #[test]
+    fn analyze_test() {
+        let text = "Bahor keldi, gullar ochildi,\n  Bog‘larga nur sochildi,\n\nQushlar kuylar daraxtda,\nYerga oltin sochildi.\n";
+        let poem = analyze(text);
+
+        let syllables: Vec<usize> = poem.lines.iter().map(|line| line.syllables).collect();
+        assert_eq!(syllables, vec![9, 7, 7, 7]);
+        assert_eq!(poem.lines[0].caesura, Some("4+5".to_string()));
+        assert_eq!(poem.lines[1].caesura, Some("4+3".to_string()));
+        assert_eq!(poem.lines[1].text, "Bog‘larga nur sochildi,");
+        assert_eq!(&text[poem.lines[1].span.clone()], poem.lines[1].text);
+        assert_eq!(poem.scheme, "AABA");
+        assert_eq!(poem.radif, None);
+        assert!(poem.lines.iter().all(|line| !line.radif));
+    }
+
+    #[test]
+    fn suffix_rhyme_test() {
+        assert_eq!(analyze("Bola keldi\nOna bordi\nOta qildi\nQiz yozdi").scheme, "ABCD");
+        assert_eq!(analyze("Gul ochildi\nOna bordi\nNur sochildi\nOta qildi").scheme, "ABAA");
+    }
+
+    #[test]
+    fn radif_test() {
+        let poem = analyze("Юртим обод бўлсин доим,\nЭлим шод бўлсин доим,\nБоғда гуллар очилсин,\nХалқим озод бўлсин доим.");
+
+        assert_eq!(poem.scheme, "AABA");
+        assert_eq!(poem.radif, Some("бўлсин доим"));
+
+        let radif: Vec<bool> = poem.lines.iter().map(|line| line.radif).collect();
+        assert_eq!(radif, vec![true, true, false, true]);
+        assert_eq!(poem.lines[1].caesura, Some("3+4".to_string()));
+    }
+
+    #[test]
+    fn get_caesura_test() {
+        assert_eq!(get_caesura(&[2, 2, 4, 3]), Some("4+4+3".to_string()));
+        assert_eq!(get_caesura(&[3, 1, 3, 1, 3]), Some("4+4+3".to_string()));
+        assert_eq!(get_caesura(&[3, 3, 2, 3]), Some("6+5".to_string()));
+        assert_eq!(get_caesura(&[5, 6]), None);
+        assert_eq!(get_caesura(&[3, 3]), None);
+    }
+
+    #[test]
+    fn get_rime_test() {
+        assert_eq!(get_rime("ochildi"), "ildi");
+        assert_eq!(get_rime("bordi"), "ordi");
+        assert_eq!(get_rime("di"), "i");
+        assert_eq!(get_rime("bulbul"), "ul");
+        assert_eq!(get_rime("daraxt"), "axt");
+        assert_eq!(get_rime("bog‘"), "og‘");
+    }
+
+    #[test]
+    fn scan_test() {
+        let text = "Kecha kelgumdur debon ul sarvi gulro‘ kelmadi,\nKo‘zing gul, so‘zing bol, yuzing oy, kelur\n\nSevib dildor, ko‘rib dildor, chaman bor";
+        let output = scan(text);
+
+        assert_eq!(output.len(), 3);
+        assert_eq!(output[0].text, "Kecha kelgumdur debon ul sarvi gulro‘ kelmadi,");
+        assert_eq!(output[0].feet, "foilotun foilotun foilotun foilun");
+
+        assert_eq!(output[1].pattern, "U--U--U--U-");
+        assert_eq!(output[1].bahr, Bahr::Mutaqorib);
+        assert_eq!(output[1].meter, "mutaqoribi musammani mahzuf");
+        assert_eq!(output[1].mismatches, 0);
+
+        assert_eq!(&text[output[2].span.clone()], output[2].text);
+        assert_eq!(output[2].pattern, "U---U---U--");
+        assert_eq!(output[2].meter, "hazaji musaddasi mahzuf");
+        assert_eq!(output[2].mismatches, 0);
+    }
+
+    #[test]
+    fn get_weights_test() {
+        let weights = |text| get_weights(&tokenize::words(text));
+
+        assert_eq!(weights("bahor"), "U-");
+        assert_eq!(weights("daraxt"), "U-");
+        assert_eq!(weights("daraxtlar"), "U-U-");
+        assert_eq!(weights("kelma"), "--");
+        assert_eq!(weights("дўст"), "-");
+        assert_eq!(weights(""), "");
+    }
+
+    #[test]
+    fn distance_test() {
+        assert_eq!(distance("U--", "U--"), 0);
+        assert_eq!(distance("U--", "---"), 1);
+        assert_eq!(distance("U---", "U--"), 1);
+        assert_eq!(distance("", "U--"), 3);
+    }
+
+    #[test]
+    fn empty_test() {
+        assert!(scan("\n").is_empty());
+
+        let poem = analyze("\n \n");
+
+        assert!(poem.lines.is_empty());
+        assert_eq!(poem.scheme, "");
+        assert_eq!(poem.radif, None);
+    }
+}
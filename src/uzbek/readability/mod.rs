@@ -0,0 +1,136 @@
+//! Functions to measure readability of Uzbek texts.
+//!
+//! Implemented for both latin and cyrillic modes.
+use crate::uzbek::tokenize::{self, TokenKind};
+
+/// Number of syllables from which a word is counted as long.
+const LONG_WORD: usize = 4;
+
+/// Base of the reading ease score, the same as in the Flesch formula.
+const BASE: f64 = 206.835;
+
+/// Weight of the average sentence length in words.
+const SENTENCE_WEIGHT: f64 = 1.52;
+
+/// Weight of the average number of syllables per word.
+const SYLLABLE_WEIGHT: f64 = 48.8;
+
+/// Readability metrics of a text returned by [`analyze`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Report {
+    /// Number of sentences.
+    pub sentences: usize,
+    /// Number of words, numbers and symbols are not counted.
+    pub words: usize,
+    /// Number of syllables in all words.
+    pub syllables: usize,
+    /// Number of words with four or more syllables.
+    pub long_words: usize,
+    /// Average number of words in a sentence.
+    pub average_sentence_length: f64,
+    /// Average number of syllables in a word.
+    pub syllables_per_word: f64,
+    /// Share of long words from 0 to 1.
+    pub long_word_ratio: f64,
+    /// Reading ease from 0 (very hard) to 100 (very easy).
+    pub score: f64,
+}
+
+/// Returns readability metrics of a text.
+///
+/// Sentences are found by [`tokenize::sentences`] and syllables by [`tokenize::syllables`].
+/// The score follows the Flesch reading ease formula
+/// `206.835 - 1.52 * ASL - 48.8 * ASW`, where ASL is the average sentence length
+/// and ASW is the number of syllables per word.
+/// The weights are adjusted for Uzbek, where words are longer than English ones
+/// because of suffixes, so that ordinary school texts with about 10 words
+/// per sentence and 2.6 syllables per word score around 65.
+/// The score is clamped to the range from 0 to 100.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::readability;
+///
+/// let report = readability::analyze("Bu olma. Men kitob o‘qiyman.");
+/// assert_eq!(report.sentences, 2);
+/// assert_eq!(report.words, 5);
+/// assert_eq!(report.syllables, 9);
+/// assert_eq!(report.average_sentence_length, 2.5);
+/// assert_eq!(report.syllables_per_word, 1.8);
+/// assert_eq!(report.score, 100.0);
+/// ```
+pub fn analyze(text: &str) -> Report {
+    let mut report = Report::default();
+
+    for span in tokenize::sentences(text) {
+        let mut has_words = false;
+
+        for token in tokenize::words(&text[span]) {
+            if token.kind != TokenKind::Word {
+                continue;
+            }
+
+            let count = tokenize::syllables(token.text).len();
+            has_words = true;
+            report.words += 1;
+            report.syllables += count;
+
+            if count >= LONG_WORD {
+                report.long_words += 1;
+            }
+        }
+
+        if has_words {
+            report.sentences += 1;
+        }
+    }
+
+    if report.words == 0 {
+        return report;
+    }
+
+    let words = report.words as f64;
+    report.average_sentence_length = words / report.sentences as f64;
+    report.syllables_per_word = report.syllables as f64 / words;
+    report.long_word_ratio = report.long_words as f64 / words;
+    report.score = (BASE - SENTENCE_WEIGHT * report.average_sentence_length - SYLLABLE_WEIGHT * report.syllables_per_word)
+        .clamp(0.0, 100.0);
+
+    report
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    #[test]
+    fn analyze_test() {
+        let text = "Respublikamizda ta’lim tizimini takomillashtirish bo‘yicha keng ko‘lamli islohotlar amalga oshirilmoqda. \
+            Umumta’lim maktablarida o‘qitish sifatini oshirishga alohida e’tibor qaratilmoqda.";
+        let report = analyze(text);
+
+        assert_eq!(report.sentences, 2);
+        assert_eq!(report.words, 18);
+        assert_eq!(report.syllables, 69);
+        assert_eq!(report.long_words, 11);
+        assert_eq!(report.average_sentence_length, 9.0);
+        assert!((report.long_word_ratio - 11.0 / 18.0).abs() < 1e-9);
+        assert!((report.score - (BASE - SENTENCE_WEIGHT * 9.0 - SYLLABLE_WEIGHT * 69.0 / 18.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn scripts_test() {
+        let latin = analyze("Bolalar maktabga borishdi. Ular kitob o‘qishdi!");
+        let cyrillic = analyze("Болалар мактабга боришди. Улар китоб ўқишди!");
+
+        assert_eq!(latin, cyrillic);
+        assert_eq!(latin.sentences, 2);
+        assert_eq!(latin.syllables, 16);
+    }
+
+    #[test]
+    fn empty_test() {
+        assert_eq!(analyze(""), Report::default());
+        assert_eq!(analyze("2024 — 15 %."), Report::default());
+    }
+}
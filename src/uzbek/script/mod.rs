@@ -0,0 +1,197 @@
+//! Letter correspondence between the cyrillic and latin Uzbek alphabets.
+//!
+//! Used internally where words of both scripts have to be compared
+//! through a shared letter model.
+use crate::uzbek::letter;
+
+/// Alphabet of Uzbek text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// Latin alphabet, "O‘zbekiston".
+    Latin,
+    /// Cyrillic alphabet, "Ўзбекистон".
+    Cyrillic,
+}
+
+const CYRILLIC_TO_LATIN: [(char, &str); 33] = [
+    ('а', "a"),
+    ('б', "b"),
+    ('в', "v"),
+    ('г', "g"),
+    ('д', "d"),
+    ('ё', "yo"),
+    ('ж', "j"),
+    ('з', "z"),
+    ('и', "i"),
+    ('й', "y"),
+    ('к', "k"),
+    ('л', "l"),
+    ('м', "m"),
+    ('н', "n"),
+    ('о', "o"),
+    ('п', "p"),
+    ('р', "r"),
+    ('с', "s"),
+    ('т', "t"),
+    ('у', "u"),
+    ('ф', "f"),
+    ('х', "x"),
+    ('ч', "ch"),
+    ('ш', "sh"),
+    ('ъ', "ʼ"),
+    ('ь', ""),
+    ('э', "e"),
+    ('ю', "yu"),
+    ('я', "ya"),
+    ('ў', "o‘"),
+    ('қ', "q"),
+    ('ғ', "g‘"),
+    ('ҳ', "h"),
+];
+
+/// Vowels written after "y" as a single cyrillic letter.
+const IOTATED: [(&str, &str); 4] = [("a", "я"), ("o", "ё"), ("u", "ю"), ("e", "е")];
+
+/// Converts cyrillic Uzbek letters of a text to latin ones.
+///
+/// Letters outside the Uzbek cyrillic alphabet are kept as they are.
+/// Capital digraphs are written as "Sh" inside a word
+/// and as "SH" when the neighbouring letter is capital too.
+pub(crate) fn to_latin(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::new();
+
+    for (i, &value) in chars.iter().enumerate() {
+        let lower = value.to_lowercase().next().unwrap_or(value);
+        let previous = if i > 0 { Some(chars[i - 1]) } else { None };
+        let next = chars.get(i + 1).copied();
+
+        let word_start = !previous.is_some_and(char::is_alphabetic);
+        let after_vowel = previous.is_some_and(|p| letter::is_vowel(&p.to_string()) || "ъьЪЬ".contains(p));
+
+        let latin = match lower {
+            'е' if word_start || after_vowel => "ye",
+            'е' => "e",
+            'ц' if after_vowel && !word_start => "ts",
+            'ц' => "s",
+            _ => match CYRILLIC_TO_LATIN.iter().find(|pair| pair.0 == lower) {
+                Some(pair) => pair.1,
+                None => {
+                    result.push(value);
+                    continue;
+                }
+            },
+        };
+
+        if value == lower {
+            result.push_str(latin);
+            continue;
+        }
+
+        let shout = next.is_some_and(char::is_uppercase)
+            || (next.is_none_or(|n| !n.is_alphabetic()) && previous.is_some_and(char::is_uppercase));
+
+        if shout {
+            result.push_str(&latin.to_uppercase());
+        } else {
+            let mut letters = latin.chars();
+            if let Some(first) = letters.next() {
+                result.extend(first.to_uppercase());
+                result.push_str(letters.as_str());
+            }
+        }
+    }
+
+    result
+}
+
+/// Converts latin Uzbek letters of a text to cyrillic ones.
+///
+/// Letters are found by [`letter::letter_indices`], so "sh", "ch", "o‘" and "g‘" with
+/// any apostrophe variant become single letters. "y" with a following vowel becomes
+/// "я", "ё", "ю" or "е", "e" at the beginning of a word or after a vowel becomes "э"
+/// and the tutuq belgisi becomes "ъ". Other chars are kept as they are.
+pub(crate) fn to_cyrillic(text: &str) -> String {
+    let letters: Vec<(usize, &str)> = letter::letter_indices(text).collect();
+    let mut result = String::new();
+    let mut position = 0;
+    let mut i = 0;
+
+    while i < letters.len() {
+        let (start, value) = letters[i];
+        let lower = letter::normalize(value);
+        let joined = |index: usize| letters.get(index).filter(|(next, _)| *next == letters[index - 1].0 + letters[index - 1].1.len());
+        let word_start = i == 0 || joined(i).is_none();
+        let previous = if word_start { None } else { Some(letter::normalize(letters[i - 1].1)) };
+        let next = joined(i + 1).map(|(_, next)| letter::normalize(next));
+
+        let iotated = next
+            .as_deref()
+            .and_then(|next| IOTATED.iter().find(|(vowel, _)| *vowel == next))
+            .filter(|_| lower == "y");
+
+        let (cyrillic, count) = match lower.as_str() {
+            _ if iotated.is_some() => (iotated.map_or("", |(_, cyrillic)| *cyrillic).to_string(), 2),
+            "e" if word_start || previous.as_deref().is_some_and(letter::is_vowel) => ("э".to_string(), 1),
+            "e" => ("е".to_string(), 1),
+            "‘" => ("ъ".to_string(), 1),
+            "ng" => ("нг".to_string(), 1),
+            _ => match CYRILLIC_TO_LATIN.iter().find(|pair| pair.1 == lower && !pair.1.is_empty()) {
+                Some(pair) => (pair.0.to_string(), 1),
+                None => (value.to_string(), 1),
+            },
+        };
+
+        result.push_str(&text[position..start]);
+
+        if value.chars().next().is_some_and(char::is_uppercase) {
+            let mut chars = cyrillic.chars();
+            if let Some(first) = chars.next() {
+                result.extend(first.to_uppercase());
+                result.push_str(chars.as_str());
+            }
+        } else {
+            result.push_str(&cyrillic);
+        }
+
+        let (last, value) = letters[i + count - 1];
+        position = last + value.len();
+        i += count;
+    }
+
+    result.push_str(&text[position..]);
+    result
+}
+
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    #[test]
+    fn to_latin_test() {
+        assert_eq!(to_latin("Алишер"), "Alisher");
+        assert_eq!(to_latin("Ўзбекистон"), "O‘zbekiston");
+        assert_eq!(to_latin("ғалаба қалам ҳаво"), "g‘alaba qalam havo");
+        assert_eq!(to_latin("Шаҳло ШАҲЛО"), "Shahlo SHAHLO");
+        assert_eq!(to_latin("Ёқуб юлдуз"), "Yoqub yulduz");
+        assert_eq!(to_latin("ер поезд маъно съезд"), "yer poyezd maʼno sʼyezd");
+        assert_eq!(to_latin("цирк милиция концерт"), "sirk militsiya konsert");
+        assert_eq!(to_latin("альбом, salom!"), "albom, salom!");
+    }
+
+    #[test]
+    fn to_cyrillic_test() {
+        assert_eq!(to_cyrillic("Alisher"), "Алишер");
+        assert_eq!(to_cyrillic("O‘zbekiston"), "Ўзбекистон");
+        assert_eq!(to_cyrillic("g'alaba qalam havo"), "ғалаба қалам ҳаво");
+        assert_eq!(to_cyrillic("Shahlo SHAHLO"), "Шаҳло ШАҲЛО");
+        assert_eq!(to_cyrillic("Yoqub yulduz yo‘q"), "Ёқуб юлдуз йўқ");
+        assert_eq!(to_cyrillic("yer ekan poeziya ma’no"), "ер экан поэзия маъно");
+        assert_eq!(to_cyrillic("dengiz, 5-sinf!"), "денгиз, 5-синф!");
+
+        for word in ["o‘qituvchi", "kitoblarimizdan", "Yoqubjon", "shoir", "yangi"] {
+            assert_eq!(letter::normalize(&to_latin(&to_cyrillic(word))), letter::normalize(word));
+        }
+    }
+}
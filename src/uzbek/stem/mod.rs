@@ -0,0 +1,354 @@
+//! Rule-based stemming of Uzbek words.
+//!
+//! Implemented for both latin and cyrillic modes.
+use crate::uzbek::{letter, morphology};
+use crate::uzbek::letter::{is_cyrillic, is_vowel};
+
+/// Minimal number of letters left in a stem.
+const MIN_LENGTH: usize = 2;
+
+/// Minimal number of letters left by a case or possessive suffix next to the stem
+/// or by a tense without a person ending in a stem missing in the lexicon,
+/// so "qalam" is not read as "qala" with "-m" and "narsa" as "nar" with "-sa".
+const MIN_BARE_LENGTH: usize = 5;
+
+/// Suffixes of a script, each with a condition on the preceding letter:
+/// "" for any letter, "V" for a vowel, "C" for a consonant, otherwise the letter itself.
+struct Suffixes {
+    case: &'static [(&'static str, &'static str)],
+    possessive: &'static [(&'static str, &'static str)],
+    plural: &'static [(&'static str, &'static str)],
+    negation: &'static str,
+    /// Present tense suffix used after negation.
+    present: &'static str,
+    /// Tense suffixes with person endings, which can follow them.
+    tenses: &'static [(&'static str, &'static str, &'static [&'static str])],
+}
+
+const LATIN: Suffixes = Suffixes {
+    case: &[
+        ("gacha", ""), ("ning", ""), ("dagi", ""), ("dan", ""),
+        ("ni", ""), ("ga", ""), ("ka", "k"), ("qa", "q"), ("da", ""),
+    ],
+    possessive: &[
+        ("ingiz", "C"), ("imiz", "C"), ("ngiz", "V"), ("miz", "V"), ("ing", "C"),
+        ("im", "C"), ("si", "V"), ("ng", "V"), ("m", "V"), ("i", "C"),
+    ],
+    plural: &[("lar", "")],
+    negation: "ma",
+    present: "y",
+    tenses: &[
+        ("a", "C", &["man", "san", "di", "miz", "siz", "dilar"]),
+        ("y", "V", &["man", "san", "di", "miz", "siz", "dilar"]),
+        ("di", "", &["m", "ng", "", "k", "ngiz", "lar"]),
+        ("sa", "", &["m", "ng", "", "k", "ngiz", "lar"]),
+        ("gan", "", &["man", "san", "", "miz", "siz", "lar"]),
+        ("kan", "k", &["man", "san", "", "miz", "siz", "lar"]),
+        ("qan", "q", &["man", "san", "", "miz", "siz", "lar"]),
+        ("yap", "", &["man", "san", "ti", "miz", "siz", "tilar"]),
+        ("moqda", "", &["man", "san", "", "miz", "siz", "lar"]),
+        ("moq", "", &[""]),
+    ],
+};
+
+const CYRILLIC: Suffixes = Suffixes {
+    case: &[
+        ("гача", ""), ("нинг", ""), ("даги", ""), ("дан", ""),
+        ("ни", ""), ("га", ""), ("ка", "к"), ("қа", "қ"), ("да", ""),
+    ],
+    possessive: &[
+        ("ингиз", "C"), ("имиз", "C"), ("нгиз", "V"), ("миз", "V"), ("инг", "C"),
+        ("им", "C"), ("си", "V"), ("нг", "V"), ("м", "V"), ("и", "C"),
+    ],
+    plural: &[("лар", "")],
+    negation: "ма",
+    present: "й",
+    tenses: &[
+        ("а", "C", &["ман", "сан", "ди", "миз", "сиз", "дилар"]),
+        ("й", "V", &["ман", "сан", "ди", "миз", "сиз", "дилар"]),
+        ("ди", "", &["м", "нг", "", "к", "нгиз", "лар"]),
+        ("са", "", &["м", "нг", "", "к", "нгиз", "лар"]),
+        ("ган", "", &["ман", "сан", "", "миз", "сиз", "лар"]),
+        ("кан", "к", &["ман", "сан", "", "миз", "сиз", "лар"]),
+        ("қан", "қ", &["ман", "сан", "", "миз", "сиз", "лар"]),
+        ("яп", "", &["ман", "сан", "ти", "миз", "сиз", "тилар"]),
+        ("моқда", "", &["ман", "сан", "", "миз", "сиз", "лар"]),
+        ("моқ", "", &[""]),
+    ],
+};
+
+/// Returns the stem of a word.
+///
+/// Case, possessive and plural suffixes of nouns, as well as tense, person
+/// and negation suffixes of verbs are stripped in the reverse order of their
+/// attachment, so "kitoblarimizdan" gives "kitob" and "bormadim" gives "bor".
+/// A stem keeps at least two letters with a vowel. A case or possessive suffix
+/// next to the stem and a tense without a person ending, as in "keldi" or
+/// "kelsalar", are stripped only when the stem is in the lexicon or has at
+/// least five letters, so "qalam" and "qalami" both give "qalam" and "parda"
+/// stays "parda". The agentive "-chi" is kept, so "o‘quvchi" and "o‘quvchilar"
+/// both give "o‘quvchi", and a final "k" or "q" voiced before a possessive vowel
+/// is restored, so "ertagi" gives "ertak".
+/// The result is lowercased and apostrophe variants are unified to ‘.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek;
+///
+/// assert_eq!(uzbek::stem("Kitoblarimizdan"), "kitob");
+/// assert_eq!(uzbek::stem("o‘qiyapman"), "o‘qi");
+/// assert_eq!(uzbek::stem("болаларнинг"), "бола");
+/// assert_eq!(uzbek::stem("uy"), "uy");
+/// ```
+pub fn stem(word: &str) -> String {
+    let word = letter::normalize(word.trim());
+    let suffixes = if word.chars().any(is_cyrillic) { &CYRILLIC } else { &LATIN };
+
+    let noun = strip_noun(&word, suffixes);
+    let verb = strip(&word, verb_endings(suffixes), true);
+
+    if letter::letters(verb).count() < letter::letters(&noun).count() {
+        verb.to_string()
+    } else {
+        noun
+    }
+}
+
+/// Strips case, possessive and plural suffixes of a noun.
+///
+/// A possessive suffix does not split the agentive "-chi", as in "o‘quvchi",
+/// and a possessive vowel restores the final "k" and "q" voiced to "g" and "g‘",
+/// as in "ertagi".
+fn strip_noun(word: &str, suffixes: &Suffixes) -> String {
+    let mut stems = vec![(word.to_string(), true)];
+
+    for (index, group) in [suffixes.case, suffixes.possessive, suffixes.plural].into_iter().enumerate() {
+        let noun = stems[stems.len() - 1].0.clone();
+        let rest = if index == 1 {
+            let entries = entries(group).filter(|(suffix, _, _)| !noun.strip_suffix(suffix.as_str()).is_some_and(is_agentive));
+            strip(&noun, entries, false)
+        } else {
+            strip(&noun, entries(group), false)
+        };
+
+        if rest.len() < noun.len() {
+            let vowel = letter::letters(&noun[rest.len()..]).next().is_some_and(is_vowel);
+            let rest = if index == 1 && vowel { devoice(rest) } else { rest.to_string() };
+            stems.push((rest, index == 2));
+        }
+    }
+
+    // a case or possessive suffix next to the stem, as in "parda" or "qalam", needs a known or a long stem
+    while let Some((noun, plural)) = stems.pop() {
+        if plural || is_bare_stem(&noun, false) {
+            return noun;
+        }
+    }
+
+    word.to_string()
+}
+
+/// Checks whether a stem ends with the agentive or ordinal "-ch" after a consonant, as in "o‘quvch".
+fn is_agentive(text: &str) -> bool {
+    let letters: Vec<&str> = letter::letters(text).collect();
+
+    matches!(letters[..], [.., previous, "ch" | "ч"] if !is_vowel(previous))
+}
+
+/// Restores the final "k" and "q" of a stem of several syllables voiced before a possessive vowel.
+fn devoice(text: &str) -> String {
+    let syllables = letter::letters(text).filter(|value| is_vowel(value)).count();
+    let Some((start, last)) = letter::letter_indices(text).last() else { return text.to_string() };
+
+    if syllables < 2 || morphology::is_lemma(text, false) {
+        return text.to_string();
+    }
+
+    let voiceless = match last {
+        "g" => "k",
+        "g‘" => "q",
+        "г" => "к",
+        "ғ" => "қ",
+        _ => return text.to_string(),
+    };
+
+    format!("{}{}", &text[..start], voiceless)
+}
+
+fn entries(group: &'static [(&'static str, &'static str)]) -> impl Iterator<Item = (String, &'static str, bool)> {
+    group.iter().map(|&(suffix, after)| (suffix.to_string(), after, false))
+}
+
+/// Returns all tense and person endings of verbs with and without negation.
+///
+/// A tense without a person ending or with the plural one, as in "narsa" and
+/// "narsalar", is often a part of a word and is marked as bare.
+fn verb_endings(suffixes: &Suffixes) -> Vec<(String, &'static str, bool)> {
+    let mut result = Vec::new();
+
+    for &(tense, after, persons) in suffixes.tenses {
+        for person in persons {
+            let bare = person.is_empty() || suffixes.plural.iter().any(|&(plural, _)| plural == *person);
+            result.push((format!("{}{}", tense, person), after, bare));
+
+            // negation takes "-y" of present tense and is not used with "-kan" and "-qan"
+            match after {
+                "C" => result.push((format!("{}{}{}", suffixes.negation, suffixes.present, person), "", false)),
+                "V" | "" => result.push((format!("{}{}{}", suffixes.negation, tense, person), "", false)),
+                _ => {}
+            }
+        }
+    }
+
+    result
+}
+
+/// Strips the longest suffix, which leaves a valid stem.
+///
+/// A suffix marked as bare needs a known or a long stem.
+fn strip<I>(word: &str, suffixes: I, verbal: bool) -> &str
+where
+    I: IntoIterator<Item = (String, &'static str, bool)>,
+{
+    let mut result = word;
+
+    for (suffix, after, bare) in suffixes {
+        let Some(rest) = word.strip_suffix(suffix.as_str()) else { continue };
+
+        if rest.len() < result.len() && is_stem(rest) && follows(rest, after) && (!bare || is_bare_stem(rest, verbal)) {
+            result = rest;
+        }
+    }
+
+    result
+}
+
+fn is_stem(text: &str) -> bool {
+    letter::letters(text).count() >= MIN_LENGTH && letter::letters(text).any(is_vowel)
+}
+
+/// Checks whether a stem left next to a suffix is in the lexicon or long enough.
+fn is_bare_stem(text: &str, verbal: bool) -> bool {
+    letter::letters(text).count() >= MIN_BARE_LENGTH || morphology::is_lemma(text, verbal)
+}
+
+/// Checks the condition on the last letter of a stem.
+fn follows(text: &str, after: &str) -> bool {
+    let Some(last) = letter::letters(text).last() else { return false };
+
+    match after {
+        "" => true,
+        "V" => is_vowel(last),
+        "C" => !is_vowel(last),
+        _ => last == after,
+    }
+}
+
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    #[test]
+    fn noun_test() {
+        assert_eq!(stem("kitoblarimizdan"), "kitob");
+        assert_eq!(stem("bolalarga"), "bola");
+        assert_eq!(stem("bolamning"), "bola");
+        assert_eq!(stem("daftaringiz"), "daftar");
+        assert_eq!(stem("maktabgacha"), "maktab");
+        assert_eq!(stem("uydagi"), "uy");
+        assert_eq!(stem("ko‘kka"), "ko‘k");
+        assert_eq!(stem("O'zbekistonda"), "o‘zbekiston");
+        assert_eq!(stem("olmalar"), "olma");
+    }
+
+    #[test]
+    fn verb_test() {
+        assert_eq!(stem("bordim"), "bor");
+        assert_eq!(stem("bormadim"), "bor");
+        assert_eq!(stem("keldi"), "kel");
+        assert_eq!(stem("kelaman"), "kel");
+        assert_eq!(stem("kelmaydi"), "kel");
+        assert_eq!(stem("yozganmiz"), "yoz");
+        assert_eq!(stem("chiqqan"), "chiq");
+        assert_eq!(stem("o‘qiyapsiz"), "o‘qi");
+        assert_eq!(stem("o‘qimoqda"), "o‘qi");
+        assert_eq!(stem("kelsangiz"), "kel");
+    }
+
+    #[test]
+    fn cyrillic_test() {
+        assert_eq!(stem("китобларимиздан"), "китоб");
+        assert_eq!(stem("кўкка"), "кўк");
+        assert_eq!(stem("бормадим"), "бор");
+        assert_eq!(stem("ўқияпман"), "ўқи");
+        assert_eq!(stem("келмайди"), "кел");
+    }
+
+    #[test]
+    fn short_test() {
+        assert_eq!(stem("olma"), "olma");
+        assert_eq!(stem("oldi"), "ol");
+        assert_eq!(stem("ona"), "ona");
+        assert_eq!(stem("da"), "da");
+        assert_eq!(stem(""), "");
+    }
+
+    #[test]
+    fn bare_noun_test() {
+        for (word, inflected) in [
+            ("salom", "salomlar"), ("qalam", "qalami"), ("odam", "odamlar"), ("olim", "olimlar"),
+            ("bilim", "bilimi"), ("ikki", "ikkisi"), ("yangi", "yangilar"), ("olti", "oltisi"),
+            ("bandi", "bandini"), ("rang", "ranglar"),
+        ] {
+            assert_eq!(stem(word), word);
+            assert_eq!(stem(inflected), word);
+        }
+
+        assert_eq!(stem("bolam"), "bola");
+        assert_eq!(stem("kitobim"), "kitob");
+        assert_eq!(stem("daftarim"), "daftar");
+        assert_eq!(stem("o‘zgartirdi"), "o‘zgartir");
+        assert_eq!(stem("қалам"), "қалам");
+    }
+
+    #[test]
+    fn bare_ending_test() {
+        for (word, inflected) in [("narsa", "narsalar"), ("keksa", "keksalar"), ("parda", "pardalar"), ("qaymoq", "qaymoqlar")] {
+            assert_eq!(stem(word), word);
+            assert_eq!(stem(inflected), word);
+        }
+
+        assert_eq!(stem("Musa"), "musa");
+        assert_eq!(stem("haqida"), "haqida");
+        assert_eq!(stem("pardada"), "parda");
+        assert_eq!(stem("kelsa"), "kel");
+        assert_eq!(stem("bordilar"), "bor");
+        assert_eq!(stem("bormoq"), "bor");
+        assert_eq!(stem("нарса"), "нарса");
+    }
+
+    #[test]
+    fn agentive_test() {
+        for word in ["o‘quvchi", "yozuvchi", "haydovchi", "ikkinchi", "birinchi"] {
+            assert_eq!(stem(word), word);
+            assert_eq!(stem(&format!("{}lar", word)), word);
+            assert_eq!(stem(&format!("{}miz", word)), word);
+        }
+
+        assert_eq!(stem("o‘quvchilarimizga"), "o‘quvchi");
+        assert_eq!(stem("ўқувчилар"), "ўқувчи");
+        assert_eq!(stem("ўқувчи"), "ўқувчи");
+    }
+
+    #[test]
+    fn voicing_test() {
+        assert_eq!(stem("ertak"), "ertak");
+        assert_eq!(stem("ertagi"), "ertak");
+        assert_eq!(stem("ertaklar"), "ertak");
+        assert_eq!(stem("yuragim"), "yurak");
+        assert_eq!(stem("qishlog‘imizda"), "qishloq");
+        assert_eq!(stem("юраги"), "юрак");
+        assert_eq!(stem("tog‘i"), "tog‘");
+    }
+}
@@ -0,0 +1,34 @@
+//! Built-in stopwords.
+//!
+//! Conjunctions, particles, postpositions, pronouns with their frequent
+//! case forms and auxiliary words. Both lists hold the same words.
+
+pub(super) const LATIN: [&str; 124] = [
+    "va", "hamda", "bilan", "uchun", "ham", "esa", "lekin", "ammo", "biroq", "yoki", "yo",
+    "balki", "chunki", "agar", "garchi", "toki", "ki", "na", "deb", "degan", "edi", "ekan",
+    "emas", "emish", "kerak", "bor", "yo‘q", "bo‘lib", "bo‘lgan", "bo‘ladi", "qilib", "etib",
+    "bu", "shu", "u", "o‘sha", "ushbu", "ana", "mana", "bular", "shular", "ular", "men", "sen",
+    "biz", "siz", "o‘z", "o‘zi", "o‘zini", "o‘zining", "mening", "menga", "meni", "mendan",
+    "sening", "senga", "seni", "bizning", "bizga", "bizni", "sizning", "sizga", "sizni",
+    "uning", "unga", "uni", "undan", "unda", "ularning", "ularga", "ularni", "buning", "bunga",
+    "buni", "bundan", "bunda", "shuning", "shunga", "shuni", "shundan", "shunda", "kim", "nima",
+    "qanday", "qaysi", "qachon", "qayerda", "nega", "necha", "qancha", "har", "hamma", "barcha",
+    "ba’zi", "hech", "bir", "eng", "juda", "ko‘p", "faqat", "hali", "yana", "endi", "hozir",
+    "keyin", "oldin", "so‘ng", "kabi", "singari", "haqida", "bo‘yicha", "orqali", "tomon",
+    "sari", "qadar", "beri", "tashqari", "ko‘ra", "mi", "chi", "ku", "da", "yu", "ya",
+];
+
+pub(super) const CYRILLIC: [&str; 124] = [
+    "ва", "ҳамда", "билан", "учун", "ҳам", "эса", "лекин", "аммо", "бироқ", "ёки", "ё", "балки",
+    "чунки", "агар", "гарчи", "токи", "ки", "на", "деб", "деган", "эди", "экан", "эмас", "эмиш",
+    "керак", "бор", "йўқ", "бўлиб", "бўлган", "бўлади", "қилиб", "этиб", "бу", "шу", "у", "ўша",
+    "ушбу", "ана", "мана", "булар", "шулар", "улар", "мен", "сен", "биз", "сиз", "ўз", "ўзи",
+    "ўзини", "ўзининг", "менинг", "менга", "мени", "мендан", "сенинг", "сенга", "сени",
+    "бизнинг", "бизга", "бизни", "сизнинг", "сизга", "сизни", "унинг", "унга", "уни", "ундан",
+    "унда", "уларнинг", "уларга", "уларни", "бунинг", "бунга", "буни", "бундан", "бунда",
+    "шунинг", "шунга", "шуни", "шундан", "шунда", "ким", "нима", "қандай", "қайси", "қачон",
+    "қаерда", "нега", "неча", "қанча", "ҳар", "ҳамма", "барча", "баъзи", "ҳеч", "бир", "энг",
+    "жуда", "кўп", "фақат", "ҳали", "яна", "энди", "ҳозир", "кейин", "олдин", "сўнг", "каби",
+    "сингари", "ҳақида", "бўйича", "орқали", "томон", "сари", "қадар", "бери", "ташқари",
+    "кўра", "ми", "чи", "ку", "да", "ю", "я",
+];
@@ -0,0 +1,173 @@
+//! Uzbek stopwords, the most frequent words carrying little meaning.
+//!
+//! Implemented for both latin and cyrillic modes.
+mod constants;
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::uzbek::letter;
+
+/// Set of stopwords used to filter word counts.
+///
+/// Words are compared lowercased with apostrophe variants unified to ‘,
+/// so "Va", "va" and "ВА" are all found in the built-in set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stopwords {
+    words: HashSet<String>,
+}
+
+impl Default for Stopwords {
+    fn default() -> Self {
+        let mut stopwords = Stopwords::empty();
+
+        for word in constants::LATIN.iter().chain(constants::CYRILLIC.iter()) {
+            stopwords.insert(word);
+        }
+
+        stopwords
+    }
+}
+
+impl Stopwords {
+    /// Creates a set with the built-in stopwords of both scripts.
+    ///
+    /// # Example
+    /// ```rust
+    /// use korrektor::uzbek::stopwords::Stopwords;
+    ///
+    /// let stopwords = Stopwords::new();
+    /// assert!(stopwords.contains("Va"));
+    /// assert!(stopwords.contains("учун"));
+    /// assert!(!stopwords.contains("kitob"));
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty set to replace the built-in stopwords with custom ones.
+    ///
+    /// # Example
+    /// ```rust
+    /// use korrektor::uzbek::stopwords::Stopwords;
+    ///
+    /// let mut stopwords = Stopwords::empty();
+    /// stopwords.insert("salom");
+    /// assert!(stopwords.contains("salom"));
+    /// assert!(!stopwords.contains("va"));
+    /// ```
+    pub fn empty() -> Self {
+        Stopwords { words: HashSet::new() }
+    }
+
+    /// Adds a stopword. Returns false if it is already in the set.
+    pub fn insert(&mut self, word: &str) -> bool {
+        let word = normalize(word);
+
+        !word.is_empty() && self.words.insert(word)
+    }
+
+    /// Removes a stopword. Returns false if it was not in the set.
+    ///
+    /// # Example
+    /// ```rust
+    /// use korrektor::uzbek::stopwords::Stopwords;
+    ///
+    /// let mut stopwords = Stopwords::new();
+    /// assert!(stopwords.remove("bor"));
+    /// assert!(!stopwords.contains("bor"));
+    /// ```
+    pub fn remove(&mut self, word: &str) -> bool {
+        self.words.remove(&normalize(word))
+    }
+
+    /// Loads stopwords from a file with one word per line.
+    ///
+    /// Empty lines and lines starting with "#" are skipped.
+    pub fn load<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let content = fs::read_to_string(path)?;
+
+        for line in content.lines() {
+            if !line.trim_start().starts_with('#') {
+                self.insert(line);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether a word is a stopword.
+    ///
+    /// Punctuation around the word is ignored, so "va," is a stopword too.
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&normalize(word))
+    }
+
+    /// Returns the number of stopwords.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Checks whether the set has no stopwords.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+/// Lowercases a word, unifies apostrophes and trims punctuation around it.
+fn normalize(word: &str) -> String {
+    let word = letter::normalize(word);
+
+    word.trim_matches(|c: char| !c.is_alphanumeric() && !letter::is_apostrophe(c)).to_string()
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    #[test]
+    fn mirrored_test() {
+        assert_eq!(constants::LATIN.len(), constants::CYRILLIC.len());
+
+        for (latin, cyrillic) in constants::LATIN.iter().zip(constants::CYRILLIC) {
+            assert_eq!(letter::normalize(&crate::uzbek::script::to_latin(cyrillic)), letter::normalize(latin));
+        }
+    }
+
+    #[test]
+    fn contains_test() {
+        let stopwords = Stopwords::new();
+
+        assert_eq!(stopwords.len(), constants::LATIN.len() * 2);
+        assert!(stopwords.contains("bilan"));
+        assert!(stopwords.contains("Uchun,"));
+        assert!(stopwords.contains("ba'zi"));
+        assert!(stopwords.contains("«Ва»"));
+        assert!(!stopwords.contains("kitob"));
+        assert!(!stopwords.contains(""));
+    }
+
+    #[test]
+    fn custom_test() {
+        let mut stopwords = Stopwords::new();
+
+        assert!(stopwords.insert("Salom"));
+        assert!(!stopwords.insert("salom"));
+        assert!(!stopwords.insert("!"));
+        assert!(stopwords.remove("va"));
+        assert!(!stopwords.remove("va"));
+        assert!(stopwords.contains("salom"));
+        assert!(!stopwords.contains("va"));
+
+        let path = std::env::temp_dir().join("korrektor_stopwords_test.txt");
+        fs::write(&path, "# custom\nkitob\n\nQalam\n").unwrap();
+        let mut stopwords = Stopwords::empty();
+        stopwords.load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(stopwords.len(), 2);
+        assert!(stopwords.contains("qalam"));
+        assert!(Stopwords::empty().is_empty());
+    }
+}
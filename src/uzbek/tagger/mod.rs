@@ -0,0 +1,282 @@
+//! Rule-based part-of-speech tagging of Uzbek sentences.
+//!
+//! Implemented for both latin and cyrillic modes.
+use std::ops::Range;
+use crate::uzbek::morphology::{self, Analysis, PartOfSpeech};
+use crate::uzbek::tokenize::{self, Token, TokenKind};
+use crate::uzbek::{letter, script, stem};
+
+/// Counter words following numerals, as in "ikki ta kitob".
+const COUNTERS: [&str; 3] = ["ta", "dona", "nafar"];
+
+/// Symbols tagged as [`PartOfSpeech::Sym`] instead of punctuation.
+const SYMBOLS: &str = "%‰$€£¥₽+−=<>№&@*^~|#§°×÷/\\";
+
+/// Endings of verb forms missing in the lexicon, the longest first.
+const VERB_ENDINGS: [&str; 28] = [
+    "moqda", "yapti", "yapman", "yapsan", "yapmiz", "yapsiz", "dingiz", "dilar",
+    "aman", "asan", "amiz", "asiz", "yman", "ysan", "ymiz", "ysiz",
+    "moq", "dim", "ding", "dik", "adi", "ydi", "gan", "kan", "qan", "di", "ib", "sa",
+];
+
+/// Endings of adjectives missing in the lexicon.
+const ADJECTIVE_ENDINGS: [&str; 8] = ["simon", "chan", "dor", "siz", "li", "iy", "gi", "ki"];
+
+/// Word with its part of speech returned by [`tag`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tagged<'a> {
+    /// Text of the token as written in the input.
+    pub text: &'a str,
+    /// Byte range of the token in the input.
+    pub span: Range<usize>,
+    /// Part of speech of the token.
+    pub pos: PartOfSpeech,
+    /// Dictionary form of a word or the token itself.
+    pub lemma: String,
+}
+
+/// Tags tokens of a text with parts of speech.
+///
+/// Text is split by [`tokenize::words`] and every word gets its parses from
+/// [`morphology::analyze`]. Words missing in the lexicon are guessed by their
+/// endings and capitalization, numerals with "-ta" like "uchta" are numerals.
+/// When a word has several parses the tag is chosen by its neighbours:
+/// a numeral and a counter word like "ta" are followed by a noun, a word before
+/// a postposition like "uchun" or "bilan" is nominal, "keyin" and "oldin" are
+/// postpositions after the ablative case, "u" and "hamma" without suffixes are
+/// determiners before a noun without case suffixes and "bor" and "yo‘q" are adjectives after a subject.
+/// Tags follow the Universal Dependencies tag set.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tagger;
+///
+/// let output = tagger::tag("Men ikki ta kitob oldim.");
+/// let tags: Vec<&str> = output.iter().map(|word| word.pos.tag()).collect();
+/// assert_eq!(tags, vec!["PRON", "NUM", "NOUN", "NOUN", "VERB", "PUNCT"]);
+/// assert_eq!(output[4].lemma, "ol");
+/// assert_eq!(output[4].span, 18..23);
+/// ```
+pub fn tag(text: &str) -> Vec<Tagged<'_>> {
+    let tokens = tokenize::words(text);
+    let options: Vec<Vec<Analysis>> = tokens
+        .iter()
+        .enumerate()
+        .map(|(index, token)| get_options(token, is_start(&tokens, index)))
+        .collect();
+
+    let mut chosen: Vec<Analysis> = Vec::with_capacity(tokens.len());
+    for (index, current) in options.iter().enumerate() {
+        let previous = chosen.last().filter(|_| !is_start(&tokens, index));
+        let next = options.get(index + 1).map_or(&[][..], Vec::as_slice);
+        chosen.push(choose(current, previous, next).clone());
+    }
+
+    tokens
+        .into_iter()
+        .zip(chosen)
+        .map(|(token, analysis)| Tagged { text: token.text, span: token.span, pos: analysis.pos, lemma: analysis.lemma })
+        .collect()
+}
+
+/// Checks whether a token starts a sentence.
+fn is_start(tokens: &[Token], index: usize) -> bool {
+    index == 0 || [".", "!", "?", "…"].contains(&tokens[index - 1].text)
+}
+
+/// Returns possible parses of a token.
+fn get_options(token: &Token, start: bool) -> Vec<Analysis> {
+    let pos = match token.kind {
+        TokenKind::Word => return get_word_options(token.text, start),
+        TokenKind::Number => PartOfSpeech::Num,
+        TokenKind::Punctuation if token.text.chars().all(|c| SYMBOLS.contains(c)) => PartOfSpeech::Sym,
+        TokenKind::Punctuation => PartOfSpeech::Punct,
+        TokenKind::Url | TokenKind::Email | TokenKind::Emoji => PartOfSpeech::Sym,
+        TokenKind::Hashtag => PartOfSpeech::X,
+    };
+
+    vec![bare(token.text.to_string(), pos)]
+}
+
+/// Returns parses of a word from the lexicon or a guessed one.
+fn get_word_options(text: &str, start: bool) -> Vec<Analysis> {
+    let key = letter::normalize(&script::to_latin(text));
+
+    if COUNTERS.contains(&key.as_str()) {
+        return vec![bare(letter::normalize(text), PartOfSpeech::Noun)];
+    }
+
+    let result = morphology::analyze(text);
+    if !result.is_empty() {
+        return result;
+    }
+
+    // numerals with "-ta", as in "uchta"
+    let numeral = text
+        .char_indices()
+        .rev()
+        .nth(1)
+        .filter(|_| key.ends_with("ta"))
+        .map(|(index, _)| morphology::analyze(&text[..index]))
+        .and_then(|parses| parses.into_iter().find(|parse| parse.pos == PartOfSpeech::Num && parse.suffixes.is_empty()));
+    if let Some(numeral) = numeral {
+        return vec![bare(numeral.lemma, PartOfSpeech::Num)];
+    }
+
+    vec![guess(text, &key, start)]
+}
+
+/// Guesses the part of speech of a word missing in the lexicon.
+fn guess(text: &str, key: &str, start: bool) -> Analysis {
+    let lemma = stem(text);
+
+    if !start && text.starts_with(char::is_uppercase) {
+        let mut chars = lemma.chars();
+        let lemma = chars.next().map_or(String::new(), |first| first.to_uppercase().chain(chars).collect());
+        return bare(lemma, PartOfSpeech::Propn);
+    }
+
+    let has_ending = |endings: &[&str]| {
+        endings.iter().any(|ending| {
+            key.strip_suffix(ending).is_some_and(|rest| letter::letters(rest).count() >= 2)
+        })
+    };
+
+    let pos = if has_ending(&VERB_ENDINGS) {
+        PartOfSpeech::Verb
+    } else if has_ending(&ADJECTIVE_ENDINGS) {
+        PartOfSpeech::Adj
+    } else {
+        PartOfSpeech::Noun
+    };
+
+    bare(lemma, pos)
+}
+
+/// Chooses a parse of a word by its neighbours.
+fn choose<'a>(options: &'a [Analysis], previous: Option<&Analysis>, next: &[Analysis]) -> &'a Analysis {
+    use PartOfSpeech::*;
+
+    let find = |tags: &[PartOfSpeech]| tags.iter().find_map(|&pos| options.iter().find(|option| option.pos == pos));
+    let has = |pos: PartOfSpeech| options.iter().any(|option| option.pos == pos);
+    let previous_pos = previous.map(|analysis| analysis.pos);
+    let previous_case = previous.and_then(get_case);
+    let counted = previous.is_some_and(|analysis| {
+        analysis.pos == Num || (analysis.pos == Noun && COUNTERS.contains(&analysis.lemma.as_str()))
+    });
+
+    let preferred = if counted {
+        // "ikki ta kitob"
+        find(&[Noun])
+    } else if !next.is_empty() && next.iter().all(|option| option.pos == Adp) {
+        // "olma bilan", "siz uchun"
+        find(&[Noun, Propn, Pron, Num])
+    } else if has(Adp) && has(Adv) {
+        // "maktabdan keyin", but "keyin keldi"
+        find(if previous_case == Some("ABL") { &[Adp] } else { &[Adv] })
+    } else if has(Det) && has(Pron) {
+        // "u kitob", but "u maktabga" and "uning kitobi"
+        let noun_follows = next.first().is_some_and(|option| {
+            [Noun, Adj, Num, Propn].contains(&option.pos) && get_case(option).is_none()
+        });
+        let determiner = options.iter().find(|option| option.pos == Det && option.suffixes.is_empty());
+        determiner.filter(|_| noun_follows).or_else(|| find(&[Pron]))
+    } else if has(Adj) && has(Verb) {
+        // "menda kitob bor", but "uyga bor"
+        let subject = previous_pos.is_some_and(|pos| [Noun, Propn, Pron].contains(&pos))
+            && !matches!(previous_case, Some("ACC" | "DAT"));
+        find(if subject { &[Adj] } else { &[Verb] })
+    } else if has(Noun) && has(Verb) {
+        // "bu olma", but "kitobni yoz"
+        match (previous_pos, previous_case) {
+            (Some(Det | Adj | Num), _) => find(&[Noun]),
+            (_, Some("ACC" | "DAT")) => find(&[Verb]),
+            _ => None,
+        }
+    } else if has(Noun) && has(Adv) {
+        // "shu kecha", but "kecha keldi"
+        find(if matches!(previous_pos, Some(Det | Adj)) { &[Noun] } else { &[Adv] })
+    } else {
+        None
+    };
+
+    preferred.unwrap_or(&options[0])
+}
+
+/// Returns the gloss of the case suffix of a parse.
+fn get_case(analysis: &Analysis) -> Option<&'static str> {
+    analysis
+        .suffixes
+        .iter()
+        .map(|suffix| suffix.gloss)
+        .find(|gloss| ["GEN", "ACC", "DAT", "LOC", "ABL", "TERM", "ATTR"].contains(gloss))
+}
+
+/// Returns a parse without suffixes.
+fn bare(lemma: String, pos: PartOfSpeech) -> Analysis {
+    Analysis { stem: lemma.clone(), lemma, pos, suffixes: Vec::new() }
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    fn tags(text: &str) -> Vec<&'static str> {
+        tag(text).iter().map(|word| word.pos.tag()).collect()
+    }
+
+    #[test]
+    fn tag_test() {
+        assert_eq!(tags("Men ikki ta kitob oldim."), vec!["PRON", "NUM", "NOUN", "NOUN", "VERB", "PUNCT"]);
+        assert_eq!(tags("Bu olma siz uchun."), vec!["DET", "NOUN", "PRON", "ADP", "PUNCT"]);
+        assert_eq!(tags("U maktabdan keyin uyga keldi."), vec!["PRON", "NOUN", "ADP", "NOUN", "VERB", "PUNCT"]);
+        assert_eq!(tags("Keyin u kitob o‘qidi."), vec!["ADV", "DET", "NOUN", "VERB", "PUNCT"]);
+        assert_eq!(tags("Uning kitobi"), vec!["PRON", "NOUN"]);
+        assert_eq!(tags("Unga kitob berdim."), vec!["PRON", "NOUN", "VERB", "PUNCT"]);
+        assert_eq!(tags("Hammaning fikri."), vec!["PRON", "NOUN", "PUNCT"]);
+        assert_eq!(tags("Menda kitob bor edi."), vec!["PRON", "NOUN", "ADJ", "AUX", "PUNCT"]);
+        assert_eq!(tags("Uyga bor!"), vec!["NOUN", "VERB", "PUNCT"]);
+        assert_eq!(tags("Kitob-ku yo‘q."), vec!["NOUN", "PUNCT", "PART", "ADJ", "PUNCT"]);
+    }
+
+    #[test]
+    fn context_test() {
+        let output = tag("Kecha men bozorga borib, uchta olma sotib oldim.");
+        let tags: Vec<&str> = output.iter().map(|word| word.pos.tag()).collect();
+        let lemmas: Vec<&str> = output.iter().map(|word| word.lemma.as_str()).collect();
+
+        assert_eq!(tags, vec!["ADV", "PRON", "NOUN", "VERB", "PUNCT", "NUM", "NOUN", "VERB", "VERB", "PUNCT"]);
+        assert_eq!(lemmas, vec!["kecha", "men", "bozor", "bor", ",", "uch", "olma", "sot", "ol", "."]);
+
+        for (word, lemma) in [("uchta", "uch"), ("Uchta", "uch"), ("UCHTA", "uch"), ("УЧТА", "уч")] {
+            let output = tag(word);
+            assert_eq!((output[0].pos.tag(), output[0].lemma.as_str()), ("NUM", lemma), "{}", word);
+        }
+    }
+
+    #[test]
+    fn unknown_test() {
+        assert_eq!(tags("Ular yugurishdi."), vec!["PRON", "VERB", "PUNCT"]);
+
+        let output = tag("Kecha Alisher Samarqandga keldi. Osh juda tuzli.");
+        let tags: Vec<&str> = output.iter().map(|word| word.pos.tag()).collect();
+
+        assert_eq!(tags, vec!["ADV", "PROPN", "PROPN", "VERB", "PUNCT", "NOUN", "ADV", "ADJ", "PUNCT"]);
+        assert_eq!(output[1].lemma, "Alisher");
+    }
+
+    #[test]
+    fn symbols_test() {
+        assert_eq!(tags("Narx 15 % oshdi: https://kun.uz"), vec!["NOUN", "NUM", "SYM", "VERB", "PUNCT", "SYM"]);
+    }
+
+    #[test]
+    fn cyrillic_test() {
+        let output = tag("Мен икки та китоб олдим.");
+        let tags: Vec<&str> = output.iter().map(|word| word.pos.tag()).collect();
+
+        assert_eq!(tags, vec!["PRON", "NUM", "NOUN", "NOUN", "VERB", "PUNCT"]);
+        assert_eq!(output[4].lemma, "ол");
+        assert_eq!(output[2].lemma, "та");
+    }
+}
@@ -0,0 +1,68 @@
+//! Built-in syllabification exceptions.
+//!
+//! Each entry is a lemma split by syllables with "-". Inside a syllable,
+//! "ng" and "нг" are a single letter. Both lists hold the same words.
+
+pub(super) const LATIN_EXCEPTIONS: [&str; 29] = [
+    "si-ngil",
+    "de-ngiz",
+    "ko‘-ngil",
+    "ye-ngil",
+    "ya-ngi",
+    "ke-ngash",
+    "ke-ngay-tir",
+    "ta-nga",
+    "i-ngich-ka",
+    "chi-ngiz",
+    "ba-ngi",
+    "o-ngi",
+    "to-ngi",
+    "ra-ngi",
+    "ja-ngi",
+    "mi-ngi",
+    "pesh-ay-von",
+    "pesh-ay-vo-na",
+    "mai-shat",
+    "bosh-og‘-riq",
+    "tish-og‘-riq",
+    "ko‘z-oy-nak",
+    "sut-e-mi-zuv-chi",
+    "tez-o-qar",
+    "tosh-o-tar",
+    "gul-ob",
+    "yer-os-ti",
+    "bosh-o-yoq",
+    "ko‘r-a-ko‘r",
+];
+
+pub(super) const CYRILLIC_EXCEPTIONS: [&str; 29] = [
+    "си-нгил",
+    "де-нгиз",
+    "кў-нгил",
+    "е-нгил",
+    "я-нги",
+    "ке-нгаш",
+    "ке-нгай-тир",
+    "та-нга",
+    "и-нгич-ка",
+    "чи-нгиз",
+    "ба-нги",
+    "о-нги",
+    "то-нги",
+    "ра-нги",
+    "жа-нги",
+    "ми-нги",
+    "пеш-ай-вон",
+    "пеш-ай-во-на",
+    "маи-шат",
+    "бош-оғ-риқ",
+    "тиш-оғ-риқ",
+    "кўз-ой-нак",
+    "сут-э-ми-зув-чи",
+    "тез-о-қар",
+    "тош-о-тар",
+    "гул-об",
+    "ер-ос-ти",
+    "бош-о-ёқ",
+    "кўр-а-кўр",
+];
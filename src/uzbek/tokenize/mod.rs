@@ -1,21 +1,187 @@
-//! Functions to split Uzbek words by syllables.
+//! Functions to split Uzbek text into words and words by syllables.
 //!
 //! Implemented according to grammar rules
 //! for both latin and cyrillic modes.
-use pcre::Pcre;
+mod constants;
+
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::OnceLock;
 use regex::Regex;
+use crate::uzbek::letter::{self, is_vowel};
+
+/// Kind of a [`Token`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Word, including apostrophes and paired words like "ota-ona".
+    Word,
+    /// Number like "1024", "3,14" or "12:30".
+    Number,
+    /// Punctuation mark or any other symbol.
+    Punctuation,
+    /// Web address starting with "http://", "https://" or "www.".
+    Url,
+    /// E-mail address.
+    Email,
+    /// Hashtag like "#o‘zbekiston".
+    Hashtag,
+    /// Emoji, including modifier and flag sequences.
+    Emoji,
+}
+
+/// Part of a text found by [`words`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    /// Text of the token as written in the input.
+    pub text: &'a str,
+    /// Kind of the token.
+    pub kind: TokenKind,
+    /// Byte range of the token in the input.
+    pub span: Range<usize>,
+}
+
+/// Kind of a [`Syllable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyllableKind {
+    /// Syllable ending with a vowel, like "chi" in "chiroyli".
+    Open,
+    /// Syllable ending with a consonant, like "roy" in "chiroyli".
+    Closed,
+}
+
+/// Syllable of a word found by [`syllables`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Syllable<'a> {
+    /// Text of the syllable as written in the input.
+    pub text: &'a str,
+    /// Byte range of the syllable in the input.
+    pub span: Range<usize>,
+    /// Whether the syllable is open or closed.
+    pub kind: SyllableKind,
+    /// Letters of the syllable as vowels and consonants, like "CVC".
+    pub pattern: String,
+}
+
+/// Syllabification with exception dictionaries.
+///
+/// Words which are not split by the grammar rules, like "de-ngiz" or
+/// compounds like "bosh-og‘-riq", are taken from a built-in dictionary
+/// for both scripts, which can be extended with custom exceptions.
+/// Exceptions also match inflected forms, so "dengizda" is split as
+/// "de-ngiz-da" and "singlim" keeps "ng" of "si-ngil" as one letter.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize::Syllabifier;
+///
+/// let mut syllabifier = Syllabifier::new();
+/// assert_eq!(syllabifier.split_word("dengizda"), "de-ngiz-da");
+///
+/// syllabifier.add_exception("maq-sad");
+/// assert_eq!(syllabifier.split_word("maqsadlar"), "maq-sad-lar");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Syllabifier {
+    exceptions: Vec<String>,
+}
+
+/// Particles written with a hyphen, which are separate words.
+const PARTICLES: [&str; 22] = [
+    "ku", "da", "chi", "u", "yu", "a", "ya", "e", "ey", "mi", "ov",
+    "ку", "да", "чи", "у", "ю", "а", "я", "е", "эй", "ми", "ов",
+];
 
-const LATIN_EXP: [(&str, &str); 7] = [
-    ("singil", "si-ngil"),
-    ("dengiz", "de-ngiz"),
-    ("pešayvon", "pe-shayvon"),
-    ("pešona", "pe-shona"),
-    ("maishat", "mai-shat"),
-    ("išingizni", "ishi-ngiz-ni"),
-    ("išingizda", "ishi-ngiz-da"),
+const WORD_PATTERN: &str = r"(?:[oOgG][ʻʼ'‘’‛′ʽ`]|\p{L}\p{M}*)(?:[oOgG][ʻʼ'‘’‛′ʽ`]|[\p{L}\p{N}]\p{M}*)*(?:[ʻʼ'‘’‛′ʽ`](?:[oOgG][ʻʼ'‘’‛′ʽ`]|[\p{L}\p{N}]\p{M}*)+)*";
+
+const TOKEN_PATTERN: &str = r#"(?P<url>(?:https?://|www\.)[^\s<>"«»]+)|(?P<email>[\w.+-]+@[\w-]+(?:\.[\w-]+)+)|(?P<hashtag>#[\p{L}\p{N}_]+(?:[ʻʼ'‘’‛′ʽ`][\p{L}\p{N}_]+)*)|(?P<number>\d+(?:[.,:]\d+)*)|(?P<word>WORD(?:-WORD)*)|(?P<emoji>\p{Regional_Indicator}{2}|\p{Extended_Pictographic}(?:\x{FE0F}|\p{Emoji_Modifier})*(?:\x{200D}\p{Extended_Pictographic}(?:\x{FE0F}|\p{Emoji_Modifier})*)*)|(?P<punctuation>\S)"#;
+
+/// Abbreviations ending with a period and whether they can end a sentence.
+///
+/// Abbreviations which can not end a sentence, like titles and initials,
+/// never split it. Others split it when the next word is capitalized.
+const ABBREVIATIONS: [(&str, bool); 58] = [
+    ("h.k.", true),
+    ("sh.k.", true),
+    ("b.", true),
+    ("mln.", true),
+    ("mlrd.", true),
+    ("trln.", true),
+    ("km.", true),
+    ("kg.", true),
+    ("sm.", true),
+    ("mm.", true),
+    ("gr.", true),
+    ("min.", true),
+    ("sek.", true),
+    ("t.y.", false),
+    ("y.", false),
+    ("yy.", false),
+    ("asr.", false),
+    ("prof.", false),
+    ("dots.", false),
+    ("akad.", false),
+    ("dr.", false),
+    ("o‘zr.", false),
+    ("sh.", false),
+    ("v.", false),
+    ("t.", false),
+    ("q.", false),
+    ("ko‘ch.", false),
+    ("mah.", false),
+    ("mil.", false),
+    ("av.", false),
+    ("r.", false),
+    ("m.", false),
+    ("ҳ.к.", true),
+    ("ш.к.", true),
+    ("б.", true),
+    ("млн.", true),
+    ("млрд.", true),
+    ("трлн.", true),
+    ("км.", true),
+    ("кг.", true),
+    ("см.", true),
+    ("мм.", true),
+    ("гр.", true),
+    ("т.й.", false),
+    ("й.", false),
+    ("йй.", false),
+    ("проф.", false),
+    ("доц.", false),
+    ("акад.", false),
+    ("ўзр.", false),
+    ("ш.", false),
+    ("в.", false),
+    ("т.", false),
+    ("қ.", false),
+    ("кўч.", false),
+    ("маҳ.", false),
+    ("мил.", false),
+    ("р.", false),
+];
+
+/// Consonant pairs of loanwords which can start a syllable, as in "abs-trakt" and "eks-press".
+///
+/// Pairs like "sp" and "st" start only words, inside a word "s" closes the syllable,
+/// as in "eks-pert" and "kons-ti-tut-si-ya".
+const ONSETS: [&str; 28] = [
+    "pr", "br", "tr", "dr", "kr", "gr", "fr", "vr", "pl", "bl", "kl", "gl", "fl", "ts",
+    "пр", "бр", "тр", "др", "кр", "гр", "фр", "вр", "пл", "бл", "кл", "гл", "фл", "тс",
 ];
 
-const CYRILLIC_EXP: [(&str, &str); 0] = [];
+/// Soft hyphen marking an optional line break.
+const SOFT_HYPHEN: char = '\u{AD}';
+
+const TERMINATORS: [&str; 4] = [".", "!", "?", "…"];
+
+const CLOSING: [&str; 7] = ["»", "\"", ")", "]", "”", "’", "'"];
+
+const DASHES: [&str; 3] = ["-", "–", "—"];
+
+/// Chars which are not part of a web address at its end.
+const URL_TRAILING: &[char] = &['.', ',', ';', ':', '!', '?', ')', ']', '}', '\'', '"', '»', '’', '”'];
 
 const A_CORRECT: [(&str, &str); 5] = [
     ("g[ʻʼ'‘’‛′ʽ`]", "ğ"),
@@ -32,201 +198,658 @@ const I_CORRECT: [(&str, &str); 4] = [
     ("č", "ch")
 ];
 
-const REPLACE_CYR: [(&str, &str); 2] = [
-    ("[аоуэияёюеў]", "V"),
-    ("[бвгджзйклмнпрстфхцчшқғҳ]", "C")
-];
-const REPLACE_LAT: [(&str, &str); 2] = [
-    ("[aoueiŏ]", "V"),
-    ("[bdfghjklmnpqrstvxyzğšč]", "C")
-];
+/// Splits a text into tokens.
+///
+/// Given a text returns its words, numbers, punctuation marks, web addresses,
+/// e-mails, hashtags and emoji with their byte ranges. Whitespace is skipped.
+/// Apostrophes of o‘, g‘ and the tutuq belgisi stay inside words,
+/// paired words like "ota-ona" are single words and hyphenated particles
+/// like "-ku" or "-chi" are split from the word they follow.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize::{self, TokenKind};
+///
+/// let output = tokenize::words("O‘zbek tili, kitob-ku!");
+/// let texts: Vec<&str> = output.iter().map(|token| token.text).collect();
+/// assert_eq!(texts, vec!["O‘zbek", "tili", ",", "kitob", "-", "ku", "!"]);
+/// assert_eq!(output[0].kind, TokenKind::Word);
+/// assert_eq!(output[0].span, 0..8);
+/// assert_eq!(output[2].kind, TokenKind::Punctuation);
+/// ```
+pub fn words(text: &str) -> Vec<Token<'_>> {
+    let re = token_regex();
+
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    while let Some(captures) = re.captures_at(text, position) {
+        let whole = captures.get(0).unwrap();
+        let mut end = whole.end();
 
+        if let Some(url) = captures.name("url") {
+            end = url.start() + url.as_str().trim_end_matches(URL_TRAILING).len();
+            push_token(&mut tokens, text, url.start()..end, TokenKind::Url);
+        } else if captures.name("email").is_some() {
+            push_token(&mut tokens, text, whole.range(), TokenKind::Email);
+        } else if captures.name("hashtag").is_some() {
+            push_token(&mut tokens, text, whole.range(), TokenKind::Hashtag);
+        } else if captures.name("number").is_some() {
+            push_token(&mut tokens, text, whole.range(), TokenKind::Number);
+        } else if captures.name("word").is_some() {
+            push_word(&mut tokens, text, whole.range());
+        } else if captures.name("emoji").is_some() {
+            push_token(&mut tokens, text, whole.range(), TokenKind::Emoji);
+        } else {
+            push_token(&mut tokens, text, whole.range(), TokenKind::Punctuation);
+        }
+
+        position = end;
+    }
+
+    tokens
+}
+
+/// Splits a text into sentences.
 ///
-/// Splits the word by syllables.
+/// Given a text returns byte ranges of its sentences without surrounding whitespace.
+/// A sentence ends with ".", "!", "?" or "…" and any closing quotes,
+/// unless the next word is not capitalized or the period belongs
+/// to an initial like "A. Navoiy" or to an abbreviation like "h.k." or "mln.".
 ///
-/// Given a String reference returns a new String
-/// containing the word separated by syllables with a delimiter.
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize;
+///
+/// let text = "A. Navoiy she'rlar, g‘azallar va h.k. yozgan. Narxi 5 mln. so‘m! Ҳа.";
+/// let output: Vec<&str> = tokenize::sentences(text).into_iter().map(|span| &text[span]).collect();
+/// assert_eq!(output, vec!["A. Navoiy she'rlar, g‘azallar va h.k. yozgan.", "Narxi 5 mln. so‘m!", "Ҳа."]);
+/// ```
+pub fn sentences(text: &str) -> Vec<Range<usize>> {
+    sentences_with(text, &[])
+}
+
+/// Splits a text into sentences with additional abbreviations.
+///
+/// Works as [`sentences`], but periods of the given abbreviations,
+/// like "ped." or "fil.f.d.", never end a sentence.
 ///
 /// # Example
 /// ```rust
 /// use korrektor::uzbek::tokenize;
 ///
-/// let output = tokenize::split_word("chiroyli");
-/// let expected = "chi-roy-li".to_string();
-/// assert_eq!(output, expected);
+/// let text = "Muallif ped. Fanlari doktori. Kitob 2020-yilda chiqqan.";
+/// let output = tokenize::sentences_with(text, &["ped."]);
+/// assert_eq!(output, vec![0..29, 30..55]);
+/// assert_eq!(&text[output[0].clone()], "Muallif ped. Fanlari doktori.");
 /// ```
-pub fn split_word(word: &str) -> String {
-    let mut result = a_correct(&word.to_string());
-    result = result.trim().to_string();
-    let mut last = result.clone();
+pub fn sentences_with(text: &str, abbreviations: &[&str]) -> Vec<Range<usize>> {
+    let tokens = words(text);
+    let mut result = Vec::new();
+    let mut start = None;
+    let mut i = 0;
 
-    let copy = result.clone();
+    while i < tokens.len() {
+        let first = *start.get_or_insert(tokens[i].span.start);
 
-    // latin implementation
-    let mut re = Pcre::compile(r"(?:\p{Latin}+)").unwrap();
-    let matches = re.matches(&copy);
+        if !is_one_of(&tokens[i], &TERMINATORS) {
+            i += 1;
+            continue;
+        }
 
-    // if found latin word
-    for _ in matches {
-        let key = LATIN_EXP.iter().find(|k| k.0 == copy);
-        if let Some(pair) = key { return pair.1.to_string(); }
+        let mut end = i;
+        while let Some(next) = tokens.get(end + 1) {
+            let attached = next.span.start == tokens[end].span.end;
+            if !attached || !(is_one_of(next, &TERMINATORS) || is_one_of(next, &CLOSING)) {
+                break;
+            }
+            end += 1;
+        }
 
-        for pair in REPLACE_LAT {
-            let re = Regex::new(pair.0).unwrap();
-            result = re.replace_all(&result, pair.1).as_ref().to_string();
+        if is_sentence_end(text, &tokens, i, end, abbreviations) {
+            result.push(first..tokens[end].span.end);
+            start = None;
         }
+
+        i = end + 1;
     }
 
-    // cyrillic implementation
-    let mut re = Pcre::compile(r"(?:\p{Cyrillic}+)").unwrap();
-    let matches = re.matches(&copy);
+    if let (Some(first), Some(last)) = (start, tokens.last()) {
+        result.push(first..last.span.end);
+    }
 
-    // if found cyrillic word
-    for _ in matches {
-        let key = CYRILLIC_EXP.iter().find(|k| k.0 == copy);
-        if let Some(pair) = key { return pair.1.to_string(); }
+    result
+}
 
-        for pair in REPLACE_CYR {
-            let re = Regex::new(pair.0).unwrap();
-            result = re.replace_all(&result, pair.1).as_ref().to_string();
-        }
-    }
+/// Finds positions where a word can be broken across lines.
+///
+/// Given a word returns byte positions of syllable boundaries that leave
+/// at least `min_left` letters before and `min_right` letters after the break.
+/// Letters like o‘, sh and ng are never split, and a single letter is never left
+/// alone on a line, so limits below 2 are treated as 2. Parts of paired words
+/// like "ota-ona" are counted separately, as they can already break at the hyphen.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize;
+///
+/// assert_eq!(tokenize::hyphenate("chiroyli", 2, 2), vec![3, 6]);
+/// assert_eq!(tokenize::hyphenate("o‘zbekiston", 2, 2), vec![5, 7, 10]);
+/// assert_eq!(tokenize::hyphenate("ona", 2, 2), Vec::<usize>::new());
+/// ```
+pub fn hyphenate(word: &str, min_left: usize, min_right: usize) -> Vec<usize> {
+    Syllabifier::new().hyphenate(word, min_left, min_right)
+}
 
-    let textmap = create_map(&result);
+/// Inserts soft hyphens into every word of a text.
+///
+/// Given a text returns a new String where U+00AD soft hyphens mark the
+/// positions found by [`hyphenate`]. Case, punctuation, whitespace and
+/// tokens which are not words, such as numbers or web addresses, are kept as they are.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize;
+///
+/// let output = tokenize::hyphenate_text("Chiroyli, o‘zbekona!", 2, 2);
+/// assert_eq!(output, "Chi\u{AD}roy\u{AD}li, o‘z\u{AD}be\u{AD}ko\u{AD}na!");
+/// ```
+pub fn hyphenate_text(text: &str, min_left: usize, min_right: usize) -> String {
+    Syllabifier::new().hyphenate_text(text, min_left, min_right)
+}
+
+/// Splits every word of a text by syllables.
+///
+/// Given a text returns a new String where syllables of each word are separated
+/// with the delimiter. Case, apostrophes, punctuation, whitespace and tokens
+/// which are not words, such as numbers or web addresses, are kept as they are.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize;
+///
+/// let output = tokenize::split_text("Chiroyli qiz, O'zbekiston!", "-");
+/// assert_eq!(output, "Chi-roy-li qiz, O'z-be-kis-ton!");
+/// ```
+pub fn split_text(text: &str, delimiter: &str) -> String {
+    Syllabifier::new().split_text(text, delimiter)
+}
+
+/// Splits the word by syllables keeping their positions.
+///
+/// Given a word returns its syllables with their original text,
+/// byte range, open or closed kind and pattern of vowels and consonants.
+/// Chars which are not letters, like punctuation or a hyphen, are left between syllables.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize::{self, SyllableKind};
+///
+/// let output = tokenize::syllables("Chiroyli");
+/// let texts: Vec<&str> = output.iter().map(|syllable| syllable.text).collect();
+/// assert_eq!(texts, vec!["Chi", "roy", "li"]);
+/// assert_eq!(output[1].span, 3..6);
+/// assert_eq!(output[1].kind, SyllableKind::Closed);
+/// assert_eq!(output[1].pattern, "CVC");
+/// ```
+pub fn syllables(word: &str) -> Vec<Syllable<'_>> {
+    Syllabifier::new().syllables(word)
+}
 
-    let mut result = String::new();
+impl Syllabifier {
+    /// Creates a syllabifier with the built-in exceptions only.
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    for i in 0..textmap.len() {
-        let first: Vec<char> = last.chars().collect();
-        let first: String = first[0..textmap[i] as usize].iter().collect();
-        let second: Vec<char> = last.chars().collect();
-        let second: String = second[textmap[i] as usize..].iter().collect();
-        last = second;
+    /// Adds an exception written as syllables separated with "-", like "de-ngiz".
+    ///
+    /// Custom exceptions take precedence over the built-in ones.
+    pub fn add_exception(&mut self, pattern: &str) {
+        let pattern = pattern.trim();
 
-        if i == 0 {
-            result = result + &first;
-        } else {
-            result = result + "-" + &first;
+        if !pattern.is_empty() {
+            self.exceptions.push(pattern.to_string());
         }
     }
 
-    i_correct(&result)
-}
+    /// Loads exceptions from a file with one exception per line.
+    ///
+    /// Empty lines and lines starting with "#" are skipped.
+    pub fn load_exceptions<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let content = fs::read_to_string(path)?;
 
-fn a_correct(text: &String) -> String {
-    let mut input = text.clone();
-    input = input.to_lowercase();
+        for line in content.lines() {
+            if !line.trim_start().starts_with('#') {
+                self.add_exception(line);
+            }
+        }
 
-    for (pattern, replacement) in A_CORRECT {
-        let re = regex::Regex::new(pattern).unwrap();
-        input = re.replace_all(&input, replacement).as_ref().to_string();
+        Ok(())
     }
 
-    input
-}
+    /// Splits the word by syllables keeping their positions.
+    ///
+    /// Works as [`syllables`] with the exceptions of this syllabifier.
+    pub fn syllables<'a>(&self, word: &'a str) -> Vec<Syllable<'a>> {
+        let letters: Vec<(usize, &str)> = letter::letter_indices(word).collect();
 
-fn i_correct(text: &String) -> String {
-    let mut input = text.clone();
+        if letters.is_empty() {
+            return Vec::new();
+        }
 
-    for (pattern, replacement) in I_CORRECT {
-        let re = regex::Regex::new(pattern).unwrap();
-        input = re.replace_all(&input, replacement).as_ref().to_string();
-    }
+        let mut best: Option<ExceptionMatch> = None;
+        let patterns = self.exceptions.iter().map(String::as_str)
+            .chain(constants::LATIN_EXCEPTIONS)
+            .chain(constants::CYRILLIC_EXCEPTIONS);
 
-    input
-}
+        for pattern in patterns {
+            if let Some(found) = match_exception(word, &letters, pattern) {
+                if best.as_ref().is_none_or(|current| found.0 > current.0) {
+                    best = Some(found);
+                }
+            }
+        }
 
-fn create_map(word: &String) -> Vec<i32> {
-    let mut text_map: Vec<i32> = Vec::new();
+        match best {
+            Some((_, letters, lengths)) => build_syllables(word, &letters, &lengths),
+            None => build_syllables(word, &letters, &rule_lengths(&letters)),
+        }
+    }
 
-    if word.is_empty() {
-        return text_map;
+    /// Splits the word by syllables.
+    ///
+    /// Works as [`split_word`] with the exceptions of this syllabifier.
+    pub fn split_word(&self, word: &str) -> String {
+        let lower = word.trim().to_lowercase();
+        let parts: Vec<&str> = self.syllables(&lower).iter().map(|syllable| syllable.text).collect();
+
+        // the delimiter keeps letters of neighbouring syllables apart, so the word is corrected at once
+        i_correct(&a_correct(&parts.join("-")))
     }
 
-    let mut vector: Vec<char> = word.chars().collect();
+    /// Splits every word of a text by syllables.
+    ///
+    /// Works as [`split_text`] with the exceptions of this syllabifier.
+    pub fn split_text(&self, text: &str, delimiter: &str) -> String {
+        let mut result = String::new();
+        let mut position = 0;
 
-    for _ in 0..vector.len() {
-        match get_split(&vector)
-        {
-            Split::One => {
-                text_map.push(1);
-                vector = vector.split_off(1);
+        for token in words(text) {
+            if token.kind != TokenKind::Word {
+                continue;
             }
-            Split::Two => {
-                text_map.push(2);
-                vector = vector.split_off(2);
+
+            let output = self.syllables(token.text);
+
+            for pair in output.windows(2) {
+                // chars between syllables, like the hyphen of "ota-ona", replace the delimiter
+                if pair[0].span.end == pair[1].span.start {
+                    let boundary = token.span.start + pair[1].span.start;
+                    result.push_str(&text[position..boundary]);
+                    result.push_str(delimiter);
+                    position = boundary;
+                }
             }
-            Split::Three => {
-                text_map.push(3);
-                vector = vector.split_off(3);
+        }
+
+        result.push_str(&text[position..]);
+        result
+    }
+
+    /// Finds positions where a word can be broken across lines.
+    ///
+    /// Works as [`hyphenate`] with the exceptions of this syllabifier.
+    pub fn hyphenate(&self, word: &str, min_left: usize, min_right: usize) -> Vec<usize> {
+        let min_left = min_left.max(2);
+        let min_right = min_right.max(2);
+        let mut breaks = Vec::new();
+
+        for syllable in self.syllables(word).into_iter().skip(1) {
+            let position = syllable.span.start;
+
+            // parts of paired words like "ota-ona" are counted on their own
+            let left = &word[word[..position].rfind('-').map_or(0, |index| index + 1)..position];
+            let right = &word[position..word[position..].find('-').map_or(word.len(), |index| position + index)];
+
+            let inside_letters = left.chars().next_back().is_some_and(char::is_alphabetic)
+                || left.ends_with(letter::is_apostrophe);
+
+            if inside_letters && letter::letters(left).count() >= min_left && letter::letters(right).count() >= min_right {
+                breaks.push(position);
             }
-            Split::Four => {
-                text_map.push(4);
-                vector = vector.split_off(4);
+        }
+
+        breaks
+    }
+
+    /// Inserts soft hyphens into every word of a text.
+    ///
+    /// Works as [`hyphenate_text`] with the exceptions of this syllabifier.
+    pub fn hyphenate_text(&self, text: &str, min_left: usize, min_right: usize) -> String {
+        let mut result = String::new();
+        let mut position = 0;
+
+        for token in words(text) {
+            if token.kind != TokenKind::Word {
+                continue;
             }
-            Split::Five => {
-                text_map.push(5);
-                vector = vector.split_off(5);
+
+            for offset in self.hyphenate(token.text, min_left, min_right) {
+                result.push_str(&text[position..token.span.start + offset]);
+                result.push(SOFT_HYPHEN);
+                position = token.span.start + offset;
             }
-            Split::No => continue
         }
+
+        result.push_str(&text[position..]);
+        result
     }
+}
 
-    text_map
+/// Number of matched letters, letters of the word and syllable lengths.
+type ExceptionMatch<'a> = (usize, Vec<(usize, &'a str)>, Vec<usize>);
+
+/// Matches the word against an exception.
+///
+/// Returns `None` if the word is not a form of the exception.
+fn match_exception<'a>(word: &'a str, letters: &[(usize, &'a str)], pattern: &str) -> Option<ExceptionMatch<'a>> {
+    let parts: Vec<Vec<String>> = pattern.split('-').map(pattern_letters).collect();
+    let lemma: Vec<String> = parts.concat();
+
+    let mut merged: Vec<(usize, &str)> = Vec::new();
+    let mut i = 0;
+    let mut j = 0;
+
+    while j < lemma.len() {
+        let Some(&(start, value)) = letters.get(i) else { break };
+
+        let normalized = letter::normalize(value);
+        // final "k" and "q" are voiced before a vowel, as in "bosh-og‘-ri-g‘im"
+        let voiced = j + 1 == lemma.len()
+            && letters.get(i + 1).is_some_and(|(_, next)| is_vowel(next))
+            && matches!((lemma[j].as_str(), normalized.as_str()), ("k", "g") | ("q", "g‘") | ("к", "г") | ("қ", "ғ"));
+
+        if normalized == lemma[j] || voiced {
+            merged.push((start, value));
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        let next = letters.get(i + 1);
+        let splits_ng = (lemma[j] == "ng" || lemma[j] == "нг")
+            && next.is_some_and(|&(position, next)| position == start + value.len() && format!("{}{}", letter::normalize(value), letter::normalize(next)) == lemma[j]);
+
+        if let (true, Some(&(position, next))) = (splits_ng, next) {
+            merged.push((start, &word[start..position + next.len()]));
+            i += 2;
+            j += 1;
+            continue;
+        }
+
+        break;
+    }
+
+    let rest = &letters[i..];
+
+    if j == lemma.len() {
+        let mut lengths: Vec<usize> = parts.iter().map(Vec::len).collect();
+
+        // suffixes are split together with the last syllable of the lemma, so a vowel suffix
+        // takes its last consonant, as in "de-ngi-zi", and consonants join it, as in "ra-ngin"
+        if !rest.is_empty() {
+            let last = lengths.pop().unwrap_or(0);
+            let mut tail = merged[merged.len() - last..].to_vec();
+            tail.extend_from_slice(rest);
+            lengths.extend(rule_lengths(&tail));
+        }
+
+        merged.extend_from_slice(rest);
+        return Some((lemma.len(), merged, lengths));
+    }
+
+    // the last vowel of the lemma drops before a vowel suffix, as in "singil" and "singlim"
+    let elided = j + 2 == lemma.len()
+        && j > 0
+        && is_vowel(&lemma[j])
+        && rest.first().is_some_and(|(_, value)| letter::normalize(value) == lemma[j + 1])
+        && rest.get(1).is_some_and(|(_, value)| is_vowel(value));
+
+    if elided {
+        merged.extend_from_slice(rest);
+        let lengths = rule_lengths(&merged);
+        return Some((lemma.len() - 1, merged, lengths));
+    }
+
+    None
+}
+
+/// Returns normalized letters of an exception syllable, where "ng" is a single letter.
+fn pattern_letters(part: &str) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+
+    for value in letter::letters(part).map(letter::normalize) {
+        match result.last_mut() {
+            Some(last) if (last == "n" && value == "g") || (last == "н" && value == "г") => last.push_str(&value),
+            _ => result.push(value),
+        }
+    }
+
+    result
 }
 
-enum Split {
-    One,
-    Two,
-    Three,
-    Four,
-    Five,
-    No,
+/// Returns syllable lengths in letters according to the grammar rules.
+fn rule_lengths(letters: &[(usize, &str)]) -> Vec<usize> {
+    let pattern: String = letters
+        .iter()
+        .enumerate()
+        .map(|(index, (_, value))| match letters.get(index + 1) {
+            _ if is_vowel(value) => 'V',
+            Some((_, next)) if !is_vowel(next) && ONSETS.contains(&(letter::normalize(value) + &letter::normalize(next)).as_str()) => 'O',
+            _ => 'C',
+        })
+        .collect();
+
+    create_map(&pattern).into_iter().map(|length| length as usize).collect()
 }
 
-fn get_split(vector: &Vec<char>) -> Split {
-    let mut result = Split::No;
-    if (letter(vector, 0) == 'V' && letter(vector, 1) != 'C') ||
-        (letter(vector, 0) == 'V' && letter(vector, 1) == 'C' && letter(vector, 2) == 'V') {
-        result = Split::One;
+/// Returns the token regex, compiled on the first call.
+fn token_regex() -> &'static Regex {
+    static TOKEN_REGEX: OnceLock<Regex> = OnceLock::new();
+
+    TOKEN_REGEX.get_or_init(|| Regex::new(&TOKEN_PATTERN.replace("WORD", WORD_PATTERN)).unwrap())
+}
+
+fn build_syllables<'a>(word: &'a str, letters: &[(usize, &str)], lengths: &[usize]) -> Vec<Syllable<'a>> {
+    let mut result = Vec::new();
+    let mut index = 0;
+
+    for &length in lengths {
+        let end = (index + length).min(letters.len());
+        if index >= end {
+            break;
+        }
+
+        let (start, _) = letters[index];
+        let (last, value) = letters[end - 1];
+        let span = start..last + value.len();
+        let pattern: String = letters[index..end]
+            .iter()
+            .map(|(_, value)| if is_vowel(value) { 'V' } else { 'C' })
+            .collect();
+        let kind = if pattern.ends_with('V') { SyllableKind::Open } else { SyllableKind::Closed };
+
+        result.push(Syllable { text: &word[span.clone()], span, kind, pattern });
+        index = end;
     }
-    if letter(vector, 0) == 'V' && letter(vector, 1) == 'C' && letter(vector, 2) != 'V' && letter(vector, 3) != 'C' {
-        result = Split::Two;
+
+    result
+}
+
+
+fn is_sentence_end(text: &str, tokens: &[Token], first: usize, last: usize, abbreviations: &[&str]) -> bool {
+    let next = match tokens.get(last + 1) {
+        Some(token) => token,
+        None => return true,
+    };
+
+    if next.span.start == tokens[last].span.end || starts_lowercase(next) {
+        return false;
     }
-    if letter(vector, 0) == 'V' && letter(vector, 1) == 'C' && letter(vector, 2) == 'C' && letter(vector, 3) != 'V' {
-        result = Split::Three;
+
+    if is_one_of(next, &DASHES) && tokens.get(last + 2).is_some_and(starts_lowercase) {
+        return false;
     }
-    if (letter(vector, 0) == 'C' && letter(vector, 1) == 'V' && letter(vector, 2) != 'C') ||
-        (letter(vector, 0) == 'C' && letter(vector, 1) == 'V' && letter(vector, 2) == 'C' && letter(vector, 3) == 'V') {
-        result = Split::Two;
+
+    let single_period = tokens[first].text == "." && !tokens[first..=last].iter().skip(1).any(|token| token.text == ".");
+    if !single_period {
+        return true;
     }
-    if letter(vector, 0) == 'C' && letter(vector, 1) == 'V' && letter(vector, 2) == 'C' && letter(vector, 3) == 'C' && letter(vector, 4) == 'V'
-        || letter(vector, 0) == 'C' && letter(vector, 1) == 'V' && letter(vector, 2) == 'C' && letter(vector, 3) != 'C' && letter(vector, 3) != 'V' {
-        result = Split::Three;
+
+    let period = &tokens[first].span;
+    let chunk_start = text[..period.start]
+        .char_indices()
+        .rev()
+        .find(|(_, value)| value.is_whitespace())
+        .map_or(0, |(index, value)| index + value.len_utf8());
+    let chunk = text[chunk_start..period.end].trim_start_matches(|value: char| !value.is_alphanumeric());
+
+    let mut letters = letter::letters(chunk);
+    let initial = letters.next().is_some_and(|value| value.starts_with(char::is_uppercase))
+        && letters.next().is_none()
+        && chunk.chars().filter(|value| value.is_alphabetic()).count() <= 2;
+    if initial {
+        return false;
     }
-    if letter(vector, 0) == 'C' && letter(vector, 1) == 'V' && letter(vector, 2) == 'C' && letter(vector, 3) == 'C' && letter(vector, 4) != 'V' {
-        result = Split::Four;
+
+    let normalized = letter::normalize(chunk);
+
+    if let Some((_, can_end)) = ABBREVIATIONS.iter().find(|(abbreviation, _)| *abbreviation == normalized) {
+        return *can_end;
     }
-    if (letter(vector, 0) == 'C' && letter(vector, 1) == 'C' && letter(vector, 2) == 'V' && letter(vector, 3) != 'C') ||
-        (letter(vector, 0) == 'C' && letter(vector, 1) == 'C' && letter(vector, 2) == 'V' && letter(vector, 3) == 'C') && letter(vector, 4) == 'V' {
-        result = Split::Three;
+
+    !abbreviations.iter().any(|abbreviation| letter::normalize(abbreviation) == normalized)
+}
+
+fn is_one_of(token: &Token, marks: &[&str]) -> bool {
+    token.kind == TokenKind::Punctuation && marks.contains(&token.text)
+}
+
+fn starts_lowercase(token: &Token) -> bool {
+    token.text.chars().next().is_some_and(char::is_lowercase)
+}
+
+fn push_token<'a>(tokens: &mut Vec<Token<'a>>, text: &'a str, span: Range<usize>, kind: TokenKind) {
+    tokens.push(Token { text: &text[span.clone()], kind, span });
+}
+
+/// Pushes a hyphenated word, splitting off trailing particles.
+fn push_word<'a>(tokens: &mut Vec<Token<'a>>, text: &'a str, span: Range<usize>) {
+    let mut end = span.end;
+    let mut particles = Vec::new();
+
+    while let Some(hyphen) = text[span.start..end].rfind('-') {
+        let hyphen = span.start + hyphen;
+        let particle = text[hyphen + 1..end].to_lowercase();
+
+        if !PARTICLES.contains(&particle.as_str()) {
+            break;
+        }
+
+        particles.push(hyphen..end);
+        end = hyphen;
     }
-    if letter(vector, 0) == 'C' && letter(vector, 1) == 'C' && letter(vector, 2) == 'V' && letter(vector, 3) == 'C' && letter(vector, 4) != 'V' && letter(vector, 5) != 'C' {
-        result = Split::Four;
+
+    push_token(tokens, text, span.start..end, TokenKind::Word);
+
+    for particle in particles.into_iter().rev() {
+        push_token(tokens, text, particle.start..particle.start + 1, TokenKind::Punctuation);
+        push_token(tokens, text, particle.start + 1..particle.end, TokenKind::Word);
     }
-    if letter(vector, 0) == 'C' && letter(vector, 1) == 'C' && letter(vector, 2) == 'V' && letter(vector, 3) == 'C' && letter(vector, 4) == 'C' && letter(vector, 5) != 'V' {
-        result = Split::Five;
+}
+
+///
+/// Splits the word by syllables.
+///
+/// Given a String reference returns a new String
+/// containing the word separated by syllables with a delimiter.
+/// The word is lowercased, use [`syllables`] to keep its case and positions.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize;
+///
+/// let output = tokenize::split_word("chiroyli");
+/// let expected = "chi-roy-li".to_string();
+/// assert_eq!(output, expected);
+/// ```
+pub fn split_word(word: &str) -> String {
+    Syllabifier::new().split_word(word)
+}
+
+fn a_correct(text: &str) -> String {
+    static A_CORRECT_REGEX: OnceLock<Vec<(Regex, &str)>> = OnceLock::new();
+
+    let corrections = A_CORRECT_REGEX.get_or_init(|| compile_corrections(&A_CORRECT));
+    correct(&text.to_lowercase(), corrections)
+}
+
+fn i_correct(text: &str) -> String {
+    static I_CORRECT_REGEX: OnceLock<Vec<(Regex, &str)>> = OnceLock::new();
+
+    let corrections = I_CORRECT_REGEX.get_or_init(|| compile_corrections(&I_CORRECT));
+    correct(text, corrections)
+}
+
+fn compile_corrections(corrections: &[(&str, &'static str)]) -> Vec<(Regex, &'static str)> {
+    corrections.iter().map(|&(pattern, replacement)| (Regex::new(pattern).unwrap(), replacement)).collect()
+}
+
+fn correct(text: &str, corrections: &[(Regex, &str)]) -> String {
+    let mut input = text.to_string();
+
+    for (re, replacement) in corrections {
+        input = re.replace_all(&input, *replacement).into_owned();
     }
 
-    result
+    input
 }
 
-fn letter(word: &Vec<char>, i: usize) -> char {
-    if i < word.len() {
-        word[i]
-    } else {
-        'n'
+/// Returns syllable lengths for a pattern of vowels "V" and consonants "C".
+///
+/// A consonant which starts a cluster allowed at the beginning of a syllable is marked as "O".
+/// One or two consonants between vowels are split as "V-CV" and "VC-CV", from a longer
+/// cluster the allowed pair goes to the next syllable, otherwise only the last consonant.
+/// Consonants before the first and after the last vowel stay in their syllables.
+fn create_map(word: &str) -> Vec<i32> {
+    let vector: Vec<char> = word.chars().collect();
+    let vowels: Vec<usize> = (0..vector.len()).filter(|&i| vector[i] == 'V').collect();
+
+    if vector.is_empty() {
+        return Vec::new();
+    }
+
+    let mut text_map: Vec<i32> = Vec::new();
+    let mut start = 0;
+
+    for pair in vowels.windows(2) {
+        let consonants = pair[1] - pair[0] - 1;
+        let onset = match consonants {
+            0 => 0,
+            1 | 2 => 1,
+            _ if vector[pair[1] - 2] == 'O' => 2,
+            _ => 1,
+        };
+
+        let end = pair[1] - onset;
+        text_map.push((end - start) as i32);
+        start = end;
     }
+
+    text_map.push((vector.len() - start) as i32);
+
+    text_map
 }
 
 #[cfg(test)]
@@ -245,7 +868,298 @@ mod as_tests {
 
     #[test]
     fn create_map_test() {
-        assert_eq!(create_map(&"CVCCVCCVCVC".to_string()), vec![3, 3, 2, 3]);
+        assert_eq!(create_map("CVCCVCCVCVC"), vec![3, 3, 2, 3]);
+        assert_eq!(create_map("CCCVCVCVCV"), vec![4, 2, 2, 2]);
+        assert_eq!(create_map("VCCOCVCC"), vec![3, 5]);
+        assert_eq!(create_map("VCCCVC"), vec![3, 3]);
+        assert_eq!(create_map("CC"), vec![2]);
+    }
+
+    #[test]
+    fn loanword_test() {
+        let corpus = [
+            ("sport", "sport"),
+            ("traktor", "trak-tor"),
+            ("strategiya", "stra-te-gi-ya"),
+            ("ekspress", "eks-press"),
+            ("kontsert", "kon-tsert"),
+            ("abstrakt", "abs-trakt"),
+            ("instruktor", "ins-truk-tor"),
+            ("spektr", "spektr"),
+            ("monstr", "monstr"),
+            ("dastlab", "dast-lab"),
+            ("do‘stlik", "do‘st-lik"),
+            ("Toshkentga", "tosh-kent-ga"),
+            ("ekspert", "eks-pert"),
+            ("transport", "trans-port"),
+            ("konstitutsiya", "kons-ti-tut-si-ya"),
+            ("эксперт", "экс-перт"),
+            ("спорт", "спорт"),
+            ("трактор", "трак-тор"),
+            ("стратегия", "стра-те-ги-я"),
+            ("экспресс", "экс-пресс"),
+            ("концерт", "кон-церт"),
+            ("абстракт", "абс-тракт"),
+        ];
+
+        for (word, expected) in corpus {
+            assert_eq!(split_word(word), expected);
+        }
+    }
+
+    fn kinds(text: &str) -> Vec<(&str, TokenKind)> {
+        words(text).into_iter().map(|token| (token.text, token.kind)).collect()
+    }
+
+    #[test]
+    fn words_test() {
+        assert_eq!(kinds("Salom, salom salom."), vec![
+            ("Salom", TokenKind::Word),
+            (",", TokenKind::Punctuation),
+            ("salom", TokenKind::Word),
+            ("salom", TokenKind::Word),
+            (".", TokenKind::Punctuation),
+        ]);
+        assert_eq!(kinds("«Ўзбекистон» ва Oʻzbekiston"), vec![
+            ("«", TokenKind::Punctuation),
+            ("Ўзбекистон", TokenKind::Word),
+            ("»", TokenKind::Punctuation),
+            ("ва", TokenKind::Word),
+            ("Oʻzbekiston", TokenKind::Word),
+        ]);
+        assert!(words("").is_empty());
+        assert!(words(" \n\t").is_empty());
+    }
+
+    #[test]
+    fn words_apostrophe_test() {
+        assert_eq!(kinds("o‘zbek ma'no qal’a bog‘"), vec![
+            ("o‘zbek", TokenKind::Word),
+            ("ma'no", TokenKind::Word),
+            ("qal’a", TokenKind::Word),
+            ("bog‘", TokenKind::Word),
+        ]);
+        assert_eq!(kinds("'kitob'"), vec![
+            ("'", TokenKind::Punctuation),
+            ("kitob", TokenKind::Word),
+            ("'", TokenKind::Punctuation),
+        ]);
+    }
+
+    #[test]
+    fn words_hyphen_test() {
+        assert_eq!(kinds("ota-ona kitob-ku kel-chi ota-ona-da"), vec![
+            ("ota-ona", TokenKind::Word),
+            ("kitob", TokenKind::Word),
+            ("-", TokenKind::Punctuation),
+            ("ku", TokenKind::Word),
+            ("kel", TokenKind::Word),
+            ("-", TokenKind::Punctuation),
+            ("chi", TokenKind::Word),
+            ("ota-ona", TokenKind::Word),
+            ("-", TokenKind::Punctuation),
+            ("da", TokenKind::Word),
+        ]);
+        assert_eq!(kinds("5-mart - bayram"), vec![
+            ("5", TokenKind::Number),
+            ("-", TokenKind::Punctuation),
+            ("mart", TokenKind::Word),
+            ("-", TokenKind::Punctuation),
+            ("bayram", TokenKind::Word),
+        ]);
+    }
+
+    #[test]
+    fn words_special_test() {
+        assert_eq!(kinds("(https://korrektor.uz/api), info@korrektor.uz #o‘zbek_tili 3,14 12:30 😀👍🏽🇺🇿"), vec![
+            ("(", TokenKind::Punctuation),
+            ("https://korrektor.uz/api", TokenKind::Url),
+            (")", TokenKind::Punctuation),
+            (",", TokenKind::Punctuation),
+            ("info@korrektor.uz", TokenKind::Email),
+            ("#o‘zbek_tili", TokenKind::Hashtag),
+            ("3,14", TokenKind::Number),
+            ("12:30", TokenKind::Number),
+            ("😀", TokenKind::Emoji),
+            ("👍🏽", TokenKind::Emoji),
+            ("🇺🇿", TokenKind::Emoji),
+        ]);
+    }
+
+    #[test]
+    fn words_span_test() {
+        let text = "Bog‘da, gul.";
+        let spans: Vec<Range<usize>> = words(text).into_iter().map(|token| token.span).collect();
+        assert_eq!(spans, vec![0..8, 8..9, 10..13, 13..14]);
+        assert_eq!(&text[0..8], "Bog‘da");
+    }
+
+    fn split(text: &str) -> Vec<&str> {
+        sentences(text).into_iter().map(|span| &text[span]).collect()
+    }
+
+    #[test]
+    fn sentences_test() {
+        assert_eq!(split("Salom. Qalaysiz? Yaxshi! Rahmat…"), vec!["Salom.", "Qalaysiz?", "Yaxshi!", "Rahmat…"]);
+        assert_eq!(split("  Men keldim.  \n\n U ketdi  "), vec!["Men keldim.", "U ketdi"]);
+        assert_eq!(split("Nega?! Bilmadim... Balki."), vec!["Nega?!", "Bilmadim...", "Balki."]);
+        assert_eq!(split("U «Keldim.» dedi. Keyin ketdi."), vec!["U «Keldim.» dedi.", "Keyin ketdi."]);
+        assert_eq!(split("Салом! Қалайсиз? Яхши."), vec!["Салом!", "Қалайсиз?", "Яхши."]);
+        assert!(split("").is_empty());
+    }
+
+    #[test]
+    fn sentences_abbreviations_test() {
+        assert_eq!(split("Olma, nok va h.k. oldim. Keyin ketdim."), vec!["Olma, nok va h.k. oldim.", "Keyin ketdim."]);
+        assert_eq!(split("Olma, nok va h.k. Keyin ketdim."), vec!["Olma, nok va h.k.", "Keyin ketdim."]);
+        assert_eq!(split("Kitob, daftar sh.k. narsalar."), vec!["Kitob, daftar sh.k. narsalar."]);
+        assert_eq!(split("A. Navoiy (t.y. 1441) buyuk shoir."), vec!["A. Navoiy (t.y. 1441) buyuk shoir."]);
+        assert_eq!(split("Sh. Rashidov nomidagi ko‘ch. Toshkentda."), vec!["Sh. Rashidov nomidagi ko‘ch. Toshkentda."]);
+        assert_eq!(split("prof. Karimov va akad. Yo‘ldoshev keldi."), vec!["prof. Karimov va akad. Yo‘ldoshev keldi."]);
+        assert_eq!(split("Narxi 5 mln. so‘m. Bu arzon."), vec!["Narxi 5 mln. so‘m.", "Bu arzon."]);
+        assert_eq!(split("Bayram 5-mart. kuni bo‘ladi. Keling."), vec!["Bayram 5-mart. kuni bo‘ladi.", "Keling."]);
+        assert_eq!(split("Бу ЎзР. ФА қарори, ш.к. Тамом."), vec!["Бу ЎзР. ФА қарори, ш.к.", "Тамом."]);
+        assert_eq!(split("Лойиҳа 2 млрд. сўм бўлди."), vec!["Лойиҳа 2 млрд. сўм бўлди."]);
+    }
+
+    #[test]
+    fn sentences_dialog_test() {
+        assert_eq!(split("— Salom! — dedi u. — Qalaysiz?"), vec!["— Salom! — dedi u.", "— Qalaysiz?"]);
+    }
+
+    #[test]
+    fn sentences_with_test() {
+        let text = "Muallif fil.f.d. Karimov. Kitob chiqdi.";
+        assert_eq!(sentences(text).len(), 3);
+        assert_eq!(sentences_with(text, &["fil.f.d."]), vec![0..25, 26..39]);
+    }
+
+    fn syllables_of(word: &str) -> Vec<&str> {
+        syllables(word).into_iter().map(|syllable| syllable.text).collect()
+    }
+
+    #[test]
+    fn syllables_text_test() {
+        assert_eq!(syllables_of("chiroyli"), vec!["chi", "roy", "li"]);
+        assert_eq!(syllables_of("O‘zbekiston"), vec!["O‘z", "be", "kis", "ton"]);
+        assert_eq!(syllables_of("dengiz"), vec!["de", "ngiz"]);
+        assert_eq!(syllables_of("ma'no"), vec!["ma'", "no"]);
+        assert_eq!(syllables_of("ota-ona"), vec!["o", "ta", "o", "na"]);
+        assert_eq!(syllables_of("«maktab»"), vec!["mak", "tab"]);
+        assert_eq!(syllables_of("brrr"), vec!["brrr"]);
+        assert_eq!(syllables_of("ЧИРОЙЛИ"), vec!["ЧИ", "РОЙ", "ЛИ"]);
+        assert!(syllables_of("").is_empty());
+    }
+
+    #[test]
+    fn syllables_test() {
+        let output = syllables("«O‘qituvchi»");
+        let spans: Vec<Range<usize>> = output.iter().map(|syllable| syllable.span.clone()).collect();
+        let kinds: Vec<SyllableKind> = output.iter().map(|syllable| syllable.kind).collect();
+        let patterns: Vec<&str> = output.iter().map(|syllable| syllable.pattern.as_str()).collect();
+
+        assert_eq!(syllables_of("«O‘qituvchi»"), vec!["O‘", "qi", "tuv", "chi"]);
+        assert_eq!(spans, vec![2..6, 6..8, 8..11, 11..14]);
+        assert_eq!(kinds, vec![SyllableKind::Open, SyllableKind::Open, SyllableKind::Closed, SyllableKind::Open]);
+        assert_eq!(patterns, vec!["V", "CV", "CVC", "CV"]);
+
+        let output = syllables("Тошкент");
+        let patterns: Vec<&str> = output.iter().map(|syllable| syllable.pattern.as_str()).collect();
+        assert_eq!(patterns, vec!["CVC", "CVCC"]);
+        assert_eq!(output[1].kind, SyllableKind::Closed);
+    }
+
+    #[test]
+    fn split_text_test() {
+        assert_eq!(split_text("Chiroyli", "-"), "Chi-roy-li");
+        assert_eq!(split_text("  QISHLOQ  xo‘jaligi.", "-"), "  QISH-LOQ  xo‘-ja-li-gi.");
+        assert_eq!(split_text("ota-ona, kitob-ku!", "-"), "o-ta-o-na, ki-tob-ku!");
+        assert_eq!(split_text("Ma'no 2023-yilda: https://kun.uz", "·"), "Ma'·no 2023-yil·da: https://kun.uz");
+        assert_eq!(split_text("Ассалому алайкум!\nҚалайсиз?", "-"), "Ас-са-ло-му а-лай-кум!\nҚа-лай-сиз?");
+        assert_eq!(split_text("", "-"), "");
+    }
+
+    #[test]
+    fn exceptions_mirrored_test() {
+        assert_eq!(constants::LATIN_EXCEPTIONS.len(), constants::CYRILLIC_EXCEPTIONS.len());
+
+        for (latin, cyrillic) in constants::LATIN_EXCEPTIONS.iter().zip(constants::CYRILLIC_EXCEPTIONS) {
+            assert_eq!(&crate::uzbek::script::to_latin(cyrillic), latin);
+            assert_eq!(split_word(latin), i_correct(&a_correct(latin)));
+            assert_eq!(split_word(cyrillic), cyrillic);
+        }
+    }
+
+    #[test]
+    fn pattern_letters_test() {
+        assert_eq!(pattern_letters("ngiz"), vec!["ng", "i", "z"]);
+        assert_eq!(pattern_letters("нгиз"), vec!["нг", "и", "з"]);
+        assert_eq!(pattern_letters("Ko'z"), vec!["k", "o‘", "z"]);
+    }
+
+    #[test]
+    fn inflected_exceptions_test() {
+        assert_eq!(split_word("dengizda"), "de-ngiz-da");
+        assert_eq!(split_word("dengizi"), "de-ngi-zi");
+        assert_eq!(split_word("singlim"), "sing-lim");
+        assert_eq!(split_word("singlisi"), "sing-li-si");
+        assert_eq!(split_word("ko‘nglim"), "ko‘ng-lim");
+        assert_eq!(split_word("boshog‘rig‘im"), "bosh-ogʻ-ri-gʻim");
+        assert_eq!(split_word("денгизда"), "де-нгиз-да");
+        assert_eq!(split_word("денгизи"), "де-нги-зи");
+        assert_eq!(split_word("синглим"), "синг-лим");
+        assert_eq!(split_word("кўзойнакни"), "кўз-ой-нак-ни");
+        assert_eq!(split_word("rangin"), "ra-ngin");
+        assert_eq!(split_word("tangam"), "ta-ngam");
+        assert_eq!(split_word("ongim"), "o-ngim");
+        assert_eq!(split_word("tongimda"), "to-ngim-da");
+        assert_eq!(split_word("тонгимда"), "то-нгим-да");
+    }
+
+    #[test]
+    fn syllabifier_test() {
+        let mut syllabifier = Syllabifier::new();
+        assert_eq!(syllabifier.split_word("internet"), "in-ter-net");
+
+        syllabifier.add_exception("  ");
+        syllabifier.add_exception("i-nter-net");
+        assert_eq!(syllabifier.split_word("Internetda"), "i-nter-net-da");
+        assert_eq!(split_word("Internetda"), "in-ter-net-da");
+        assert_eq!(syllabifier.split_text("Internet, internetda!", "-"), "I-nter-net, i-nter-net-da!");
+        assert_eq!(split_text("Internet", "-"), "In-ter-net");
+        assert_eq!(syllabifier.hyphenate("internet", 2, 2), vec![5]);
+        assert_eq!(hyphenate("internet", 2, 2), vec![2, 5]);
+        assert_eq!(syllabifier.hyphenate_text("Internetda", 2, 2), "Inter\u{AD}net\u{AD}da");
+
+        let path = std::env::temp_dir().join("korrektor_syllabifier_test.txt");
+        std::fs::write(&path, "# maxsus so‘zlar\n\nma-o-rif\nма-о-риф\n").unwrap();
+
+        let mut syllabifier = Syllabifier::new();
+        syllabifier.load_exceptions(&path).unwrap();
+        assert_eq!(syllabifier.split_word("maorifga"), "ma-o-rif-ga");
+        assert_eq!(syllabifier.split_word("маорифи"), "ма-о-ри-фи");
+        assert!(syllabifier.load_exceptions(path.with_extension("missing")).is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn hyphenate_test() {
+        assert_eq!(hyphenate("Shahlo", 2, 2), vec![4]);
+        assert_eq!(hyphenate("dengiz", 2, 2), vec![2]);
+        assert_eq!(hyphenate("ona", 1, 1), Vec::<usize>::new());
+        assert_eq!(hyphenate("o‘qituvchi", 2, 2), vec![6, 9]);
+        assert_eq!(hyphenate("o‘qituvchi", 3, 3), vec![6]);
+        assert_eq!(hyphenate("ota-ona", 2, 2), Vec::<usize>::new());
+        assert_eq!(hyphenate("bolalar-chaqalar", 2, 2), vec![2, 4, 11, 13]);
+        assert_eq!(hyphenate("ўқитувчи", 2, 2), vec![6, 12]);
+    }
+
+    #[test]
+    fn hyphenate_text_test() {
+        assert_eq!(hyphenate_text("Maktab 2023-yilda, https://maktab.uz", 2, 2),
+                   "Mak\u{AD}tab 2023-yil\u{AD}da, https://maktab.uz");
+        assert_eq!(hyphenate_text("", 2, 2), "");
     }
 
     #[test]
@@ -253,5 +1167,8 @@ mod as_tests {
         assert_eq!(split_word("singil"), "si-ngil");
         assert_eq!(split_word("chiroyli"), "chi-roy-li");
         assert_eq!(split_word("чиройли"), "чи-рой-ли");
+        assert_eq!(split_word("Chiroyli"), "chi-roy-li");
+        assert_eq!(split_word("O'zbekiston"), "o‘z-be-kis-ton");
+        assert_eq!(split_word(" ma'no "), "maʼ-no");
     }
 }
\ No newline at end of file